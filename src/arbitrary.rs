@@ -0,0 +1,70 @@
+#[cfg(not(feature = "arbitrary"))]
+compile_error!("expected `arbitrary` to be enabled");
+
+use arbitrary::{Arbitrary, Error, Result, Unstructured};
+
+use crate::{boxed::NonEmptyBoxedSlice, slice::NonEmptyBytes, vec::NonEmptyVec};
+
+impl<'a> Arbitrary<'a> for &'a NonEmptyBytes {
+    fn arbitrary(unstructured: &mut Unstructured<'a>) -> Result<Self> {
+        // NOTE: at least one byte is always taken in order to guarantee non-emptiness
+        let len = unstructured.arbitrary_len::<u8>()?.max(1);
+
+        let bytes = unstructured.bytes(len)?;
+
+        // SAFETY: `len` is non-zero, so are the bytes taken
+        Ok(unsafe { NonEmptyBytes::from_slice_unchecked(bytes) })
+    }
+
+    fn arbitrary_take_rest(unstructured: Unstructured<'a>) -> Result<Self> {
+        NonEmptyBytes::from_slice(unstructured.take_rest()).ok_or(Error::NotEnoughData)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, None)
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for NonEmptyVec<T> {
+    fn arbitrary(unstructured: &mut Unstructured<'a>) -> Result<Self> {
+        // NOTE: the first item is always generated in order to guarantee non-emptiness
+        let mut output = Self::single(T::arbitrary(unstructured)?);
+
+        for item in unstructured.arbitrary_iter()? {
+            output.push(item?);
+        }
+
+        Ok(output)
+    }
+
+    fn arbitrary_take_rest(mut unstructured: Unstructured<'a>) -> Result<Self> {
+        // NOTE: the first item is always generated in order to guarantee non-emptiness
+        let mut output = Self::single(T::arbitrary(&mut unstructured)?);
+
+        for item in unstructured.arbitrary_take_rest_iter()? {
+            output.push(item?);
+        }
+
+        Ok(output)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        let (lower, _) = T::size_hint(depth);
+
+        (lower, None)
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for NonEmptyBoxedSlice<T> {
+    fn arbitrary(unstructured: &mut Unstructured<'a>) -> Result<Self> {
+        NonEmptyVec::arbitrary(unstructured).map(NonEmptyVec::into_non_empty_boxed_slice)
+    }
+
+    fn arbitrary_take_rest(unstructured: Unstructured<'a>) -> Result<Self> {
+        NonEmptyVec::arbitrary_take_rest(unstructured).map(NonEmptyVec::into_non_empty_boxed_slice)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        NonEmptyVec::<T>::size_hint(depth)
+    }
+}
@@ -0,0 +1,200 @@
+//! Non-empty archived vectors, provided by [`rkyv`].
+
+#[cfg(not(feature = "rkyv"))]
+compile_error!("expected `rkyv` to be enabled");
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("expected either `std` or `alloc` to be enabled");
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec::Vec;
+
+use core::{fmt, ops::Deref};
+
+use rkyv::{
+    Archive, Archived, Deserialize, Place, Portable, Serialize,
+    bytecheck::CheckBytes,
+    rancor::{Fallible, Source, fail},
+    ser::{Allocator, Writer},
+    vec::{ArchivedVec, VecResolver},
+};
+
+use crate::{
+    boxed::NonEmptyBoxedSlice,
+    slice::{EmptySlice, NonEmptySlice},
+    vec::NonEmptyVec,
+};
+
+/// Represents archived non-empty vectors, [`ArchivedVec<T>`] that are never empty.
+///
+/// Both [`NonEmptyVec<T>`] and [`NonEmptyBoxedSlice<T>`] are archived as this type.
+///
+/// Validation (via [`CheckBytes`]) ensures that the archived vector is non-empty.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_slice::{ArchivedNonEmptyVec, NonEmptyVec, non_empty_vec};
+/// use rkyv::rancor::Error;
+///
+/// let non_empty: NonEmptyVec<u32> = non_empty_vec![13, 42, 69];
+///
+/// let bytes = rkyv::to_bytes::<Error>(&non_empty).unwrap();
+///
+/// let archived = rkyv::access::<ArchivedNonEmptyVec<rkyv::Archived<u32>>, Error>(&bytes).unwrap();
+///
+/// assert_eq!(archived.len().get(), 3);
+///
+/// let deserialized = rkyv::deserialize::<NonEmptyVec<u32>, Error>(archived).unwrap();
+///
+/// assert_eq!(deserialized, non_empty);
+///
+/// let empty: Vec<u32> = Vec::new();
+///
+/// let bytes = rkyv::to_bytes::<Error>(&empty).unwrap();
+///
+/// let result = rkyv::access::<ArchivedNonEmptyVec<rkyv::Archived<u32>>, Error>(&bytes);
+///
+/// assert!(result.is_err());
+/// ```
+#[repr(transparent)]
+pub struct ArchivedNonEmptyVec<T> {
+    inner: ArchivedVec<T>,
+}
+
+// SAFETY: `Self` is `repr(transparent)` over `ArchivedVec<T>`, which is `Portable`
+unsafe impl<T: Portable> Portable for ArchivedNonEmptyVec<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for ArchivedNonEmptyVec<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_archived_vec().fmt(formatter)
+    }
+}
+
+impl<T> ArchivedNonEmptyVec<T> {
+    /// Returns the contained [`ArchivedVec<T>`].
+    #[must_use]
+    pub const fn as_archived_vec(&self) -> &ArchivedVec<T> {
+        &self.inner
+    }
+
+    /// Returns the archived items as [`NonEmptySlice<T>`].
+    #[must_use]
+    pub fn as_non_empty_slice(&self) -> &NonEmptySlice<T> {
+        // SAFETY: archived vectors are non-empty either by construction or by validation
+        unsafe { NonEmptySlice::from_slice_unchecked(self.as_archived_vec().as_slice()) }
+    }
+
+    /// Resolves [`Self`] from the given non-empty slice.
+    pub fn resolve_from_non_empty_slice<U: Archive<Archived = T>>(
+        non_empty: &NonEmptySlice<U>,
+        resolver: VecResolver,
+        out: Place<Self>,
+    ) {
+        // SAFETY: `Self` is `repr(transparent)`, so it is safe to cast
+        let inner = unsafe { out.cast_unchecked::<ArchivedVec<T>>() };
+
+        ArchivedVec::resolve_from_slice(non_empty.as_slice(), resolver, inner);
+    }
+
+    /// Serializes [`Self`] from the given non-empty slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer error if serializing any of the items fails.
+    pub fn serialize_from_non_empty_slice<
+        U: Serialize<S, Archived = T>,
+        S: Fallible + Allocator + Writer + ?Sized,
+    >(
+        non_empty: &NonEmptySlice<U>,
+        serializer: &mut S,
+    ) -> Result<VecResolver, S::Error> {
+        ArchivedVec::serialize_from_slice(non_empty.as_slice(), serializer)
+    }
+}
+
+impl<T> Deref for ArchivedNonEmptyVec<T> {
+    type Target = NonEmptySlice<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_empty_slice()
+    }
+}
+
+// SAFETY: the contained vector is checked first, and then it is checked to be non-empty
+unsafe impl<T, C: Fallible + ?Sized> CheckBytes<C> for ArchivedNonEmptyVec<T>
+where
+    ArchivedVec<T>: CheckBytes<C>,
+    C::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+        let inner = value.cast::<ArchivedVec<T>>();
+
+        // SAFETY: `Self` is `repr(transparent)`, so the pointer is valid for `ArchivedVec<T>`
+        unsafe { ArchivedVec::check_bytes(inner, context)? };
+
+        // SAFETY: the archived vector was checked above
+        if unsafe { &*inner }.is_empty() {
+            fail!(EmptySlice);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Archive> Archive for NonEmptyVec<T> {
+    type Archived = ArchivedNonEmptyVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedNonEmptyVec::resolve_from_non_empty_slice(self.as_non_empty_slice(), resolver, out);
+    }
+}
+
+impl<T: Serialize<S>, S: Fallible + Allocator + Writer + ?Sized> Serialize<S> for NonEmptyVec<T> {
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedNonEmptyVec::serialize_from_non_empty_slice(self.as_non_empty_slice(), serializer)
+    }
+}
+
+impl<T: Archive, D: Fallible + ?Sized> Deserialize<NonEmptyVec<T>, D>
+    for ArchivedNonEmptyVec<Archived<T>>
+where
+    ArchivedVec<Archived<T>>: Deserialize<Vec<T>, D>,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<NonEmptyVec<T>, D::Error> {
+        let vec = self.as_archived_vec().deserialize(deserializer)?;
+
+        // SAFETY: archived vectors are non-empty, so are the deserialized ones
+        Ok(unsafe { NonEmptyVec::new_unchecked(vec) })
+    }
+}
+
+impl<T: Archive> Archive for NonEmptyBoxedSlice<T> {
+    type Archived = ArchivedNonEmptyVec<T::Archived>;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        ArchivedNonEmptyVec::resolve_from_non_empty_slice(self, resolver, out);
+    }
+}
+
+impl<T: Serialize<S>, S: Fallible + Allocator + Writer + ?Sized> Serialize<S>
+    for NonEmptyBoxedSlice<T>
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedNonEmptyVec::serialize_from_non_empty_slice(self, serializer)
+    }
+}
+
+impl<T: Archive, D: Fallible + ?Sized> Deserialize<NonEmptyBoxedSlice<T>, D>
+    for ArchivedNonEmptyVec<Archived<T>>
+where
+    ArchivedVec<Archived<T>>: Deserialize<Vec<T>, D>,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<NonEmptyBoxedSlice<T>, D::Error> {
+        let non_empty: NonEmptyVec<T> = self.deserialize(deserializer)?;
+
+        Ok(non_empty.into_non_empty_boxed_slice())
+    }
+}
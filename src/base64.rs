@@ -0,0 +1,102 @@
+//! Base64 encoding of non-empty bytes.
+
+#[cfg(not(feature = "base64"))]
+compile_error!("expected `base64` to be enabled");
+
+use core::fmt;
+
+use crate::slice::NonEmptyBytes;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+const PADDING: u8 = b'=';
+
+/// Represents adapters displaying non-empty bytes as padded base64 strings,
+/// using the standard alphabet.
+///
+/// Both [`Display`] and [`Debug`] are implemented, writing four characters per three bytes.
+///
+/// This `struct` is created by the [`base64`] method on [`NonEmptyBytes`].
+///
+/// [`Display`]: fmt::Display
+/// [`Debug`]: fmt::Debug
+/// [`base64`]: NonEmptyBytes::base64
+#[derive(Clone, Copy)]
+pub struct Base64<'a> {
+    bytes: &'a NonEmptyBytes,
+}
+
+impl<'a> Base64<'a> {
+    /// Constructs [`Self`].
+    #[must_use]
+    pub const fn new(bytes: &'a NonEmptyBytes) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the underlying bytes.
+    #[must_use]
+    pub const fn get(self) -> &'a NonEmptyBytes {
+        self.bytes
+    }
+}
+
+const fn encode(value: u32) -> u8 {
+    ALPHABET[(value & 0x3F) as usize]
+}
+
+impl fmt::Display for Base64<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for chunk in self.bytes.as_slice().chunks(3) {
+            let first = u32::from(chunk[0]);
+            let second = chunk.get(1).copied().map_or(0, u32::from);
+            let third = chunk.get(2).copied().map_or(0, u32::from);
+
+            let value = first << 16 | second << 8 | third;
+
+            let mut quad = [
+                encode(value >> 18),
+                encode(value >> 12),
+                encode(value >> 6),
+                encode(value),
+            ];
+
+            if chunk.len() < 3 {
+                quad[3] = PADDING;
+            }
+
+            if chunk.len() < 2 {
+                quad[2] = PADDING;
+            }
+
+            // SAFETY: the alphabet and the padding are always valid UTF-8
+            formatter.write_str(unsafe { str::from_utf8_unchecked(&quad) })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Base64<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, formatter)
+    }
+}
+
+impl NonEmptyBytes {
+    /// Returns the adapter displaying the bytes as padded base64 string,
+    /// using the standard alphabet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    ///
+    /// let bytes = NonEmptyBytes::from_slice(b"nekit").unwrap();
+    ///
+    /// assert_eq!(bytes.base64().to_string(), "bmVraXQ=");
+    /// ```
+    #[must_use]
+    pub const fn base64(&self) -> Base64<'_> {
+        Base64::new(self)
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(not(feature = "bincode"))]
+compile_error!("expected `bincode` to be enabled");
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec::Vec;
+
+use bincode::{
+    BorrowDecode, Encode,
+    de::BorrowDecoder,
+    enc::Encoder,
+    error::{DecodeError, EncodeError},
+};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use bincode::{Decode, de::Decoder};
+
+use crate::slice::{EMPTY_SLICE, NonEmptyBytes, NonEmptySlice};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::{
+    boxed::NonEmptyBoxedSlice,
+    vec::{EMPTY_VEC, NonEmptyVec},
+};
+
+impl<T: Encode> Encode for NonEmptySlice<T> {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.as_slice().encode(encoder)
+    }
+}
+
+// NOTE: `Encode` is implemented for `&U` and `Box<U>`, provided `U: Encode + ?Sized`
+// `NonEmptySlice<T>` is `Encode`, therefore `&NonEmptySlice<T>` and `NonEmptyBoxedSlice<T>` are too
+
+// NOTE: `bincode` only supports borrowing bytes, so `&NonEmptySlice<T>` can not be decoded
+// for arbitrary `T`; decode `NonEmptyVec<T>` instead
+
+impl<'de: 'a, 'a, C> BorrowDecode<'de, C> for &'a NonEmptyBytes {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = C>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        let maybe_empty = <&[u8]>::borrow_decode(decoder)?;
+
+        NonEmptyBytes::from_slice(maybe_empty).ok_or(DecodeError::Other(EMPTY_SLICE))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Encode> Encode for NonEmptyVec<T> {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.as_vec().encode(encoder)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<C, T: Decode<C>> Decode<C> for NonEmptyVec<T> {
+    fn decode<D: Decoder<Context = C>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let maybe_empty = Vec::decode(decoder)?;
+
+        Self::new(maybe_empty).map_err(|_| DecodeError::Other(EMPTY_VEC))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<'de, C, T: BorrowDecode<'de, C>> BorrowDecode<'de, C> for NonEmptyVec<T> {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = C>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        let maybe_empty = Vec::borrow_decode(decoder)?;
+
+        Self::new(maybe_empty).map_err(|_| DecodeError::Other(EMPTY_VEC))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<C, T: Decode<C>> Decode<C> for NonEmptyBoxedSlice<T> {
+    fn decode<D: Decoder<Context = C>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let non_empty_vec = NonEmptyVec::decode(decoder)?;
+
+        Ok(non_empty_vec.into_non_empty_boxed_slice())
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<'de, C, T: BorrowDecode<'de, C>> BorrowDecode<'de, C> for NonEmptyBoxedSlice<T> {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = C>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        let non_empty_vec = NonEmptyVec::borrow_decode(decoder)?;
+
+        Ok(non_empty_vec.into_non_empty_boxed_slice())
+    }
+}
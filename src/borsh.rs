@@ -0,0 +1,46 @@
+#[cfg(not(feature = "borsh"))]
+compile_error!("expected `borsh` to be enabled");
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("expected either `std` or `alloc` to be enabled");
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec::Vec;
+
+use borsh::{
+    BorshDeserialize, BorshSerialize,
+    io::{Error, ErrorKind, Read, Result, Write},
+};
+
+use crate::{
+    slice::NonEmptySlice,
+    vec::{EMPTY_VEC, NonEmptyVec},
+};
+
+impl<T: BorshSerialize> BorshSerialize for NonEmptySlice<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_slice().serialize(writer)
+    }
+}
+
+// NOTE: `BorshSerialize` is implemented for `&U` and `Box<U>`, provided `U: BorshSerialize + ?Sized`
+// `NonEmptySlice<T>` is `BorshSerialize`, therefore `&NonEmptySlice<T>` and `NonEmptyBoxedSlice<T>`
+// are too
+
+impl<T: BorshSerialize> BorshSerialize for NonEmptyVec<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_vec().serialize(writer)
+    }
+}
+
+impl<T: BorshDeserialize> BorshDeserialize for NonEmptyVec<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let maybe_empty = Vec::deserialize_reader(reader)?;
+
+        Self::new(maybe_empty).map_err(|_| Error::new(ErrorKind::InvalidData, EMPTY_VEC))
+    }
+}
+
+// NOTE: `BorshDeserialize` is implemented for `Box<U>`, provided `U::Owned: BorshDeserialize`
+// `NonEmptySlice<T>` is owned as `NonEmptyVec<T>` (given `T: Clone`), which is `BorshDeserialize`,
+// therefore `NonEmptyBoxedSlice<T>` is as well
@@ -0,0 +1,382 @@
+//! Non-empty [`Vec<T>`] with bounded length.
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("expected either `std` or `alloc` to be enabled");
+
+#[cfg(feature = "std")]
+use std::vec::IntoIter;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec::{IntoIter, Vec};
+
+use core::{
+    fmt,
+    ops::{Deref, DerefMut},
+    slice::{Iter, IterMut},
+};
+
+use non_empty_iter::IntoNonEmptyIterator;
+use non_zero_size::Size;
+use thiserror::Error;
+
+use crate::{iter::IntoNonEmptyIter, slice::NonEmptySlice, vec::NonEmptyVec};
+
+/// The error message used when the length bound is exceeded.
+pub const BOUND_EXCEEDED: &str = "the length bound is exceeded";
+
+/// Represents errors returned when the length bound of [`NonEmptyBoundedVec<T>`] is exceeded.
+///
+/// Holds the value that could not be added, along with the bound itself.
+#[derive(Error)]
+#[error("{BOUND_EXCEEDED} (bound is {bound})")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_slice::bounded),
+        help("make sure the length does not exceed the bound")
+    )
+)]
+pub struct BoundExceeded<T> {
+    value: T,
+    bound: Size,
+}
+
+// NOTE: the value is omitted, so that the error can be debugged regardless of `T`
+
+impl<T> fmt::Debug for BoundExceeded<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct(stringify!(BoundExceeded))
+            .field(stringify!(bound), &self.bound)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> BoundExceeded<T> {
+    // NOTE: this is private to prevent creating this error with values that fit the bound
+    pub(crate) const fn new(value: T, bound: Size) -> Self {
+        Self { value, bound }
+    }
+
+    /// Returns the contained value that could not be added.
+    #[must_use]
+    pub fn get(self) -> T {
+        self.value
+    }
+
+    /// Returns the length bound that was exceeded.
+    #[must_use]
+    pub const fn bound(&self) -> Size {
+        self.bound
+    }
+}
+
+/// Represents non-empty vectors with length bounded by the given [`Size`].
+///
+/// The length of the vector is always within `1..=bound`.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_slice::{NonEmptyBoundedVec, const_size, non_empty_vec};
+///
+/// let mut bounded = NonEmptyBoundedVec::new(non_empty_vec![1, 2], const_size!(3)).unwrap();
+///
+/// assert!(bounded.push(3).is_ok());
+///
+/// let error = bounded.push(4).unwrap_err();
+///
+/// assert_eq!(error.get(), 4);
+///
+/// assert_eq!(bounded, [1, 2, 3]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NonEmptyBoundedVec<T> {
+    inner: NonEmptyVec<T>,
+    bound: Size,
+}
+
+impl<T> NonEmptyBoundedVec<T> {
+    /// Constructs [`Self`], provided the length of the vector does not exceed the bound.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoundExceeded<NonEmptyVec<T>>`] containing the vector if it is too long.
+    pub fn new(
+        non_empty: NonEmptyVec<T>,
+        bound: Size,
+    ) -> Result<Self, BoundExceeded<NonEmptyVec<T>>> {
+        if non_empty.len() > bound {
+            return Err(BoundExceeded::new(non_empty, bound));
+        }
+
+        // SAFETY: the length of the vector does not exceed the bound
+        Ok(unsafe { Self::new_unchecked(non_empty, bound) })
+    }
+
+    /// Constructs [`Self`] without checking the length of the vector against the bound.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the length of the vector does not exceed the bound.
+    pub const unsafe fn new_unchecked(non_empty: NonEmptyVec<T>, bound: Size) -> Self {
+        Self {
+            inner: non_empty,
+            bound,
+        }
+    }
+
+    /// Constructs [`Self`] containing the single value provided.
+    pub fn single(value: T, bound: Size) -> Self {
+        // SAFETY: the bound is non-zero, so one value always fits
+        unsafe { Self::new_unchecked(NonEmptyVec::single(value), bound) }
+    }
+
+    /// Returns the length bound of the vector.
+    #[must_use]
+    pub const fn bound(&self) -> Size {
+        self.bound
+    }
+
+    /// Returns the length of the vector as [`Size`].
+    #[must_use]
+    pub const fn len(&self) -> Size {
+        self.inner.len()
+    }
+
+    /// Checks if the vector is empty. Always returns [`false`].
+    ///
+    /// This method is marked as deprecated since the vector is never empty.
+    #[must_use]
+    #[deprecated = "this vector is never empty"]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Checks whether the length of the vector has reached the bound.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.len() == self.bound
+    }
+
+    /// Returns the number of values that can be added before reaching the bound.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.bound.get() - self.len().get()
+    }
+
+    /// Returns the contained non-empty vector.
+    #[must_use]
+    pub const fn as_non_empty_vec(&self) -> &NonEmptyVec<T> {
+        &self.inner
+    }
+
+    /// Returns the contained slice as [`NonEmptySlice<T>`].
+    #[must_use]
+    pub const fn as_non_empty_slice(&self) -> &NonEmptySlice<T> {
+        self.inner.as_non_empty_slice()
+    }
+
+    /// Returns the contained slice as mutable [`NonEmptySlice<T>`].
+    ///
+    /// Mutating the slice can not change its length, so the bound is always respected.
+    pub const fn as_non_empty_mut_slice(&mut self) -> &mut NonEmptySlice<T> {
+        self.inner.as_non_empty_mut_slice()
+    }
+
+    /// Returns the contained slice.
+    #[must_use]
+    pub const fn as_slice(&self) -> &[T] {
+        self.inner.as_slice()
+    }
+
+    /// Returns the contained non-empty vector, consuming [`Self`].
+    #[must_use]
+    pub fn into_non_empty_vec(self) -> NonEmptyVec<T> {
+        self.inner
+    }
+
+    /// Returns the contained vector, consuming [`Self`].
+    #[must_use]
+    pub fn into_vec(self) -> Vec<T> {
+        self.inner.into_vec()
+    }
+
+    /// Appends the given value to the end of the vector, unless the bound would be exceeded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoundExceeded<T>`] containing the value if the vector is full.
+    pub fn push(&mut self, value: T) -> Result<(), BoundExceeded<T>> {
+        if self.is_full() {
+            return Err(BoundExceeded::new(value, self.bound));
+        }
+
+        self.inner.push(value);
+
+        Ok(())
+    }
+
+    /// Inserts the given value at the specified index, unless the bound would be exceeded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoundExceeded<T>`] containing the value if the vector is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: T) -> Result<(), BoundExceeded<T>> {
+        if self.is_full() {
+            return Err(BoundExceeded::new(value, self.bound));
+        }
+
+        self.inner.insert(index, value);
+
+        Ok(())
+    }
+
+    /// Appends the given slice to the end of the vector, unless the bound would be exceeded.
+    ///
+    /// In case of errors, the vector remains unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoundExceeded<&[T]>`] containing the slice if it does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::{NonEmptyBoundedVec, const_size};
+    ///
+    /// let mut bounded = NonEmptyBoundedVec::single(1, const_size!(3));
+    ///
+    /// assert!(bounded.extend_from_slice(&[2, 3, 4]).is_err());
+    /// assert!(bounded.extend_from_slice(&[2, 3]).is_ok());
+    ///
+    /// assert_eq!(bounded, [1, 2, 3]);
+    /// ```
+    pub fn extend_from_slice<'a>(&mut self, slice: &'a [T]) -> Result<(), BoundExceeded<&'a [T]>>
+    where
+        T: Clone,
+    {
+        if slice.len() > self.remaining() {
+            return Err(BoundExceeded::new(slice, self.bound));
+        }
+
+        self.inner.extend_from(slice);
+
+        Ok(())
+    }
+
+    /// Removes the last item from the vector and returns it,
+    /// or [`None`] if the vector would become empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.inner.pop()
+    }
+
+    /// Shortens the vector, keeping the first `len` items and dropping the rest.
+    pub fn truncate(&mut self, len: Size) {
+        self.inner.truncate(len);
+    }
+
+    /// Changes the bound of the vector, unless the current length exceeds the new bound.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BoundExceeded<Size>`] containing the current length if it exceeds the new bound.
+    pub fn set_bound(&mut self, bound: Size) -> Result<(), BoundExceeded<Size>> {
+        let len = self.len();
+
+        if len > bound {
+            return Err(BoundExceeded::new(len, bound));
+        }
+
+        self.bound = bound;
+
+        Ok(())
+    }
+}
+
+impl<T> Deref for NonEmptyBoundedVec<T> {
+    type Target = NonEmptySlice<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_empty_slice()
+    }
+}
+
+impl<T> DerefMut for NonEmptyBoundedVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_non_empty_mut_slice()
+    }
+}
+
+impl<T> AsRef<NonEmptySlice<T>> for NonEmptyBoundedVec<T> {
+    fn as_ref(&self) -> &NonEmptySlice<T> {
+        self.as_non_empty_slice()
+    }
+}
+
+impl<T> AsRef<[T]> for NonEmptyBoundedVec<T> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> From<NonEmptyBoundedVec<T>> for NonEmptyVec<T> {
+    fn from(bounded: NonEmptyBoundedVec<T>) -> Self {
+        bounded.into_non_empty_vec()
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for NonEmptyBoundedVec<T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<[U]> for NonEmptyBoundedVec<T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T> IntoIterator for NonEmptyBoundedVec<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a NonEmptyBoundedVec<T> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut NonEmptyBoundedVec<T> {
+    type Item = &'a mut T;
+
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> IntoNonEmptyIterator for NonEmptyBoundedVec<T> {
+    type IntoNonEmptyIter = IntoNonEmptyIter<T>;
+
+    fn into_non_empty_iter(self) -> Self::IntoNonEmptyIter {
+        self.inner.into_non_empty_iter()
+    }
+}
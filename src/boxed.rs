@@ -3,7 +3,10 @@
 #[cfg(not(any(feature = "std", feature = "alloc")))]
 compile_error!("expected either `std` or `alloc` to be enabled");
 
-use core::mem::MaybeUninit;
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::MaybeUninit,
+};
 
 #[cfg(feature = "std")]
 use std::vec::IntoIter;
@@ -40,7 +43,7 @@ pub const EMPTY_BOXED_SLICE: &str = "the boxed slice is empty";
 
 /// Similar to [`EmptyVec<T>`], but contains the empty boxed slice provided.
 #[derive(Error)]
-#[error("{EMPTY_BOXED_SLICE}")]
+#[error("{}", format::Contextual::new(*.context, EMPTY_BOXED_SLICE))]
 #[cfg_attr(
     feature = "diagnostics",
     derive(miette::Diagnostic),
@@ -51,6 +54,7 @@ pub const EMPTY_BOXED_SLICE: &str = "the boxed slice is empty";
 )]
 pub struct EmptyBoxedSlice<T> {
     boxed: Box<[T]>,
+    context: Option<&'static str>,
 }
 
 format::debug!(EmptyBoxedSlice, boxed);
@@ -60,7 +64,24 @@ pub type EmptyBoxedBytes = EmptyBoxedSlice<u8>;
 
 impl<T> EmptyBoxedSlice<T> {
     pub(crate) const fn new(boxed: Box<[T]>) -> Self {
-        Self { boxed }
+        Self {
+            boxed,
+            context: None,
+        }
+    }
+
+    /// Attaches the given context to [`Self`], which is then included in the error message.
+    #[must_use]
+    pub const fn with_context(mut self, context: &'static str) -> Self {
+        self.context = Some(context);
+
+        self
+    }
+
+    /// Returns the context attached to [`Self`], if any.
+    #[must_use]
+    pub const fn context(&self) -> Option<&'static str> {
+        self.context
     }
 
     /// Returns the contained empty boxed slice.
@@ -69,10 +90,15 @@ impl<T> EmptyBoxedSlice<T> {
         self.boxed
     }
 
-    /// Constructs [`Self`] from [`EmptyVec<T>`].
+    /// Constructs [`Self`] from [`EmptyVec<T>`], preserving its context.
     #[must_use]
     pub fn from_empty_vec(empty: EmptyVec<T>) -> Self {
-        Self::new(empty.get().into_boxed_slice())
+        let context = empty.context();
+
+        Self {
+            boxed: empty.get().into_boxed_slice(),
+            context,
+        }
     }
 
     /// Converts [`Self`] into [`EmptyVec<T>`].
@@ -82,6 +108,39 @@ impl<T> EmptyBoxedSlice<T> {
     }
 }
 
+// NOTE: `Borrow<NonEmptySlice<T>>` is provided by the standard library
+
+/// Hashing non-empty boxed slices is consistent with hashing slices,
+/// meaning that non-empty boxed slices can be looked up in hash maps via slices.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use non_empty_slice::{NonEmptyBoxedBytes, NonEmptyBytes};
+///
+/// let nekit = NonEmptyBytes::from_slice(b"nekit").unwrap();
+///
+/// let mut map: HashMap<NonEmptyBoxedBytes, i32> = HashMap::new();
+///
+/// map.insert(nekit.into(), 42);
+///
+/// assert_eq!(map.get(b"nekit".as_slice()), Some(&42));
+/// assert_eq!(map.get(nekit), Some(&42));
+/// ```
+impl<T> Borrow<[T]> for NonEmptyBoxedSlice<T> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T> BorrowMut<[T]> for NonEmptyBoxedSlice<T> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
 impl<T> From<NonEmptyBoxedSlice<T>> for Box<[T]> {
     fn from(boxed: NonEmptyBoxedSlice<T>) -> Self {
         boxed.into_boxed_slice()
@@ -144,6 +203,12 @@ impl<T: Clone> TryFrom<&mut [T]> for NonEmptyBoxedSlice<T> {
     }
 }
 
+impl<T, const N: usize> From<[T; N]> for NonEmptyBoxedSlice<T> {
+    fn from(array: [T; N]) -> Self {
+        NonEmptyVec::from_array(array).into_non_empty_boxed_slice()
+    }
+}
+
 impl<T: Clone> From<&NonEmptySlice<T>> for NonEmptyBoxedSlice<T> {
     fn from(non_empty: &NonEmptySlice<T>) -> Self {
         non_empty.to_non_empty_vec().into_non_empty_boxed_slice()
@@ -156,6 +221,30 @@ impl<T: Clone> From<&mut NonEmptySlice<T>> for NonEmptyBoxedSlice<T> {
     }
 }
 
+impl<T: PartialEq<U>, U> PartialEq<NonEmptySlice<U>> for NonEmptyBoxedSlice<T> {
+    fn eq(&self, other: &NonEmptySlice<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<NonEmptyBoxedSlice<U>> for NonEmptySlice<T> {
+    fn eq(&self, other: &NonEmptyBoxedSlice<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<NonEmptyVec<U>> for NonEmptyBoxedSlice<T> {
+    fn eq(&self, other: &NonEmptyVec<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<NonEmptyBoxedSlice<U>> for NonEmptyVec<T> {
+    fn eq(&self, other: &NonEmptyBoxedSlice<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
 impl<T: Clone> Clone for NonEmptyBoxedSlice<T> {
     fn clone(&self) -> Self {
         self.to_non_empty_vec().into_non_empty_boxed_slice()
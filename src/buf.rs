@@ -0,0 +1,648 @@
+//! Non-empty byte buffers, provided by [`bytes`].
+
+#[cfg(not(feature = "bytes"))]
+compile_error!("expected `bytes` to be enabled");
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("expected either `std` or `alloc` to be enabled");
+
+use core::{
+    borrow::{Borrow, BorrowMut},
+    ops::{Deref, DerefMut},
+};
+
+use bytes::{Buf, Bytes, BytesMut};
+use non_zero_size::Size;
+use thiserror::Error;
+
+use crate::{slice::NonEmptyBytes, vec::NonEmptyByteVec};
+
+/// The error message used when the shared bytes are empty.
+pub const EMPTY_SHARED_BYTES: &str = "the shared bytes are empty";
+
+/// The error message used when the mutable bytes are empty.
+pub const EMPTY_BYTES_MUT: &str = "the mutable bytes are empty";
+
+/// Similar to [`EmptySlice`], but holds the empty [`Bytes`] provided.
+///
+/// [`EmptySlice`]: crate::slice::EmptySlice
+#[derive(Debug, Error)]
+#[error("{EMPTY_SHARED_BYTES}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_slice::buf::shared),
+        help("make sure the shared bytes are non-empty")
+    )
+)]
+pub struct EmptySharedBytes {
+    bytes: Bytes,
+}
+
+impl EmptySharedBytes {
+    // NOTE: this is private to prevent creating this error with non-empty bytes
+    pub(crate) const fn new(bytes: Bytes) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the contained empty bytes.
+    #[must_use]
+    pub fn get(self) -> Bytes {
+        self.bytes
+    }
+}
+
+/// Similar to [`EmptySlice`], but holds the empty [`BytesMut`] provided.
+///
+/// [`EmptySlice`]: crate::slice::EmptySlice
+#[derive(Debug, Error)]
+#[error("{EMPTY_BYTES_MUT}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_slice::buf::mutable),
+        help("make sure the mutable bytes are non-empty")
+    )
+)]
+pub struct EmptyBytesMut {
+    bytes: BytesMut,
+}
+
+impl EmptyBytesMut {
+    // NOTE: this is private to prevent creating this error with non-empty bytes
+    pub(crate) const fn new(bytes: BytesMut) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the contained empty bytes.
+    #[must_use]
+    pub fn get(self) -> BytesMut {
+        self.bytes
+    }
+}
+
+/// Represents non-empty [`Bytes`] values.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_slice::{NonEmptyBytes, NonEmptySharedBytes, non_empty_vec};
+/// use non_zero_size::const_size;
+///
+/// let mut frame = NonEmptySharedBytes::from(non_empty_vec![1, 2, 3, 4, 5]);
+///
+/// let header = frame.split_to(const_size!(2));
+///
+/// assert_eq!(header.as_slice(), [1, 2]);
+/// assert_eq!(frame.as_slice(), [3, 4, 5]);
+///
+/// assert!(frame.split_to_checked(const_size!(3)).is_none());
+///
+/// frame.advance(2);
+///
+/// assert_eq!(frame.as_slice(), [5]);
+///
+/// assert!(frame.advance_checked(1).is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptySharedBytes {
+    inner: Bytes,
+}
+
+/// Represents non-empty [`BytesMut`] values.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_slice::{NonEmptyBytes, NonEmptyBytesMut};
+///
+/// let mut buffer = NonEmptyBytesMut::from_non_empty_bytes(NonEmptyBytes::from_slice(b"nekit").unwrap());
+///
+/// buffer.extend_from_slice(b"dev");
+///
+/// let bytes = buffer.freeze();
+///
+/// assert_eq!(bytes.as_slice(), b"nekitdev");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptyBytesMut {
+    inner: BytesMut,
+}
+
+impl Borrow<NonEmptyBytes> for NonEmptySharedBytes {
+    fn borrow(&self) -> &NonEmptyBytes {
+        self.as_non_empty_bytes()
+    }
+}
+
+impl Borrow<[u8]> for NonEmptySharedBytes {
+    fn borrow(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl TryFrom<Bytes> for NonEmptySharedBytes {
+    type Error = EmptySharedBytes;
+
+    fn try_from(bytes: Bytes) -> Result<Self, Self::Error> {
+        Self::new(bytes)
+    }
+}
+
+impl From<NonEmptySharedBytes> for Bytes {
+    fn from(non_empty: NonEmptySharedBytes) -> Self {
+        non_empty.into_bytes()
+    }
+}
+
+impl From<NonEmptyByteVec> for NonEmptySharedBytes {
+    fn from(non_empty: NonEmptyByteVec) -> Self {
+        // SAFETY: the vector is non-empty by construction, so are the bytes
+        unsafe { Self::new_unchecked(non_empty.into_vec().into()) }
+    }
+}
+
+impl From<NonEmptyBytesMut> for NonEmptySharedBytes {
+    fn from(non_empty: NonEmptyBytesMut) -> Self {
+        non_empty.freeze()
+    }
+}
+
+impl From<&'static NonEmptyBytes> for NonEmptySharedBytes {
+    fn from(non_empty: &'static NonEmptyBytes) -> Self {
+        Self::from_static(non_empty)
+    }
+}
+
+impl AsRef<Self> for NonEmptySharedBytes {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl AsRef<NonEmptyBytes> for NonEmptySharedBytes {
+    fn as_ref(&self) -> &NonEmptyBytes {
+        self.as_non_empty_bytes()
+    }
+}
+
+impl AsRef<[u8]> for NonEmptySharedBytes {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl Deref for NonEmptySharedBytes {
+    type Target = NonEmptyBytes;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_empty_bytes()
+    }
+}
+
+impl NonEmptySharedBytes {
+    /// Constructs [`Self`], provided that the [`Bytes`] provided are non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptySharedBytes`] if the provided bytes are empty.
+    pub fn new(bytes: Bytes) -> Result<Self, EmptySharedBytes> {
+        if bytes.is_empty() {
+            return Err(EmptySharedBytes::new(bytes));
+        }
+
+        // SAFETY: the bytes are non-empty at this point
+        Ok(unsafe { Self::new_unchecked(bytes) })
+    }
+
+    /// Constructs [`Self`] without checking that the [`Bytes`] are non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the bytes are non-empty.
+    #[must_use]
+    pub const unsafe fn new_unchecked(inner: Bytes) -> Self {
+        Self { inner }
+    }
+
+    /// Constructs [`Self`] from the static non-empty bytes, without copying.
+    #[must_use]
+    pub const fn from_static(non_empty: &'static NonEmptyBytes) -> Self {
+        // SAFETY: the bytes are non-empty by construction
+        unsafe { Self::new_unchecked(Bytes::from_static(non_empty.as_slice())) }
+    }
+
+    /// Constructs [`Self`] from the given non-empty bytes via copying.
+    #[must_use]
+    pub fn copy_from_non_empty_bytes(non_empty: &NonEmptyBytes) -> Self {
+        // SAFETY: the bytes are non-empty by construction
+        unsafe { Self::new_unchecked(Bytes::copy_from_slice(non_empty.as_slice())) }
+    }
+
+    /// Returns the contained [`Bytes`] behind immutable reference.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &Bytes {
+        &self.inner
+    }
+
+    /// Returns the contained [`Bytes`].
+    #[must_use]
+    pub fn into_bytes(self) -> Bytes {
+        self.inner
+    }
+
+    /// Extracts the slice containing the entire bytes.
+    #[must_use]
+    pub fn as_slice(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    /// Returns the contained bytes as [`NonEmptyBytes`].
+    #[must_use]
+    pub fn as_non_empty_bytes(&self) -> &NonEmptyBytes {
+        // SAFETY: the bytes are non-empty by construction
+        unsafe { NonEmptyBytes::from_slice_unchecked(self.as_slice()) }
+    }
+
+    /// Checks if the bytes are empty. Always returns [`false`].
+    ///
+    /// This method is marked as deprecated since the bytes are never empty.
+    #[must_use]
+    #[deprecated = "these bytes are never empty"]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the length of the bytes as [`Size`].
+    #[must_use]
+    pub fn len(&self) -> Size {
+        let len = self.as_bytes().len();
+
+        // SAFETY: the bytes are non-empty by construction
+        unsafe { Size::new_unchecked(len) }
+    }
+
+    /// Splits the bytes into two at the given non-zero index,
+    /// returning the bytes before the index and retaining the rest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than or equal to the length of the bytes.
+    pub fn split_to(&mut self, at: Size) -> Self {
+        self.split_to_checked(at)
+            .expect("split index should be less than the length")
+    }
+
+    /// Similar to [`split_to`], but returns [`None`] instead of panicking
+    /// if `at` is greater than or equal to the length of the bytes.
+    ///
+    /// [`split_to`]: Self::split_to
+    pub fn split_to_checked(&mut self, at: Size) -> Option<Self> {
+        (at < self.len())
+            // SAFETY: splitting at non-zero index less than the length keeps both parts non-empty
+            .then(|| unsafe { Self::new_unchecked(self.inner.split_to(at.get())) })
+    }
+
+    /// Splits the bytes into two at the given non-zero index,
+    /// returning the bytes after the index and retaining the rest.
+    ///
+    /// The index has to be non-zero to guarantee the bytes would remain non-empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided index is out of bounds.
+    pub fn split_off(&mut self, at: Size) -> Bytes {
+        self.inner.split_off(at.get())
+    }
+
+    /// Shortens the bytes, keeping the first `len` bytes and dropping the rest.
+    pub fn truncate(&mut self, len: Size) {
+        self.inner.truncate(len.get());
+    }
+
+    /// Advances the bytes by `count`, discarding the bytes before it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is greater than or equal to the length of the bytes.
+    pub fn advance(&mut self, count: usize) {
+        self.advance_checked(count)
+            .expect("advance count should be less than the length");
+    }
+
+    /// Similar to [`advance`], but returns [`None`] instead of panicking
+    /// if `count` is greater than or equal to the length of the bytes.
+    ///
+    /// [`advance`]: Self::advance
+    pub fn advance_checked(&mut self, count: usize) -> Option<()> {
+        (count < self.len().get()).then(|| self.inner.advance(count))
+    }
+}
+
+impl Borrow<NonEmptyBytes> for NonEmptyBytesMut {
+    fn borrow(&self) -> &NonEmptyBytes {
+        self.as_non_empty_bytes()
+    }
+}
+
+impl BorrowMut<NonEmptyBytes> for NonEmptyBytesMut {
+    fn borrow_mut(&mut self) -> &mut NonEmptyBytes {
+        self.as_non_empty_mut_bytes()
+    }
+}
+
+impl Borrow<[u8]> for NonEmptyBytesMut {
+    fn borrow(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl BorrowMut<[u8]> for NonEmptyBytesMut {
+    fn borrow_mut(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+}
+
+impl TryFrom<BytesMut> for NonEmptyBytesMut {
+    type Error = EmptyBytesMut;
+
+    fn try_from(bytes: BytesMut) -> Result<Self, Self::Error> {
+        Self::new(bytes)
+    }
+}
+
+impl From<NonEmptyBytesMut> for BytesMut {
+    fn from(non_empty: NonEmptyBytesMut) -> Self {
+        non_empty.into_bytes_mut()
+    }
+}
+
+impl From<&NonEmptyBytes> for NonEmptyBytesMut {
+    fn from(non_empty: &NonEmptyBytes) -> Self {
+        Self::from_non_empty_bytes(non_empty)
+    }
+}
+
+impl AsRef<Self> for NonEmptyBytesMut {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl AsMut<Self> for NonEmptyBytesMut {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl AsRef<NonEmptyBytes> for NonEmptyBytesMut {
+    fn as_ref(&self) -> &NonEmptyBytes {
+        self.as_non_empty_bytes()
+    }
+}
+
+impl AsMut<NonEmptyBytes> for NonEmptyBytesMut {
+    fn as_mut(&mut self) -> &mut NonEmptyBytes {
+        self.as_non_empty_mut_bytes()
+    }
+}
+
+impl AsRef<[u8]> for NonEmptyBytesMut {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl AsMut<[u8]> for NonEmptyBytesMut {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+}
+
+impl Deref for NonEmptyBytesMut {
+    type Target = NonEmptyBytes;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_empty_bytes()
+    }
+}
+
+impl DerefMut for NonEmptyBytesMut {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_non_empty_mut_bytes()
+    }
+}
+
+impl Extend<u8> for NonEmptyBytesMut {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iterable: I) {
+        // SAFETY: extending can not make the bytes empty
+        unsafe {
+            self.as_mut_bytes_mut().extend(iterable);
+        }
+    }
+}
+
+impl<'a> Extend<&'a u8> for NonEmptyBytesMut {
+    fn extend<I: IntoIterator<Item = &'a u8>>(&mut self, iterable: I) {
+        // SAFETY: extending can not make the bytes empty
+        unsafe {
+            self.as_mut_bytes_mut().extend(iterable);
+        }
+    }
+}
+
+impl NonEmptyBytesMut {
+    /// Constructs [`Self`], provided that the [`BytesMut`] provided are non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyBytesMut`] if the provided bytes are empty.
+    pub fn new(bytes: BytesMut) -> Result<Self, EmptyBytesMut> {
+        if bytes.is_empty() {
+            return Err(EmptyBytesMut::new(bytes));
+        }
+
+        // SAFETY: the bytes are non-empty at this point
+        Ok(unsafe { Self::new_unchecked(bytes) })
+    }
+
+    /// Constructs [`Self`] without checking that the [`BytesMut`] are non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the bytes are non-empty.
+    #[must_use]
+    pub const unsafe fn new_unchecked(inner: BytesMut) -> Self {
+        Self { inner }
+    }
+
+    /// Constructs [`Self`] from the given non-empty bytes via copying.
+    #[must_use]
+    pub fn from_non_empty_bytes(non_empty: &NonEmptyBytes) -> Self {
+        // SAFETY: the bytes are non-empty by construction
+        unsafe { Self::new_unchecked(BytesMut::from(non_empty.as_slice())) }
+    }
+
+    /// Returns the contained [`BytesMut`] behind immutable reference.
+    #[must_use]
+    pub const fn as_bytes_mut(&self) -> &BytesMut {
+        &self.inner
+    }
+
+    /// Returns the contained [`BytesMut`] behind mutable reference.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the returned bytes remain non-empty.
+    #[must_use]
+    pub const unsafe fn as_mut_bytes_mut(&mut self) -> &mut BytesMut {
+        &mut self.inner
+    }
+
+    /// Returns the contained [`BytesMut`].
+    #[must_use]
+    pub fn into_bytes_mut(self) -> BytesMut {
+        self.inner
+    }
+
+    /// Extracts the slice containing the entire bytes.
+    #[must_use]
+    pub fn as_slice(&self) -> &[u8] {
+        self.as_bytes_mut()
+    }
+
+    /// Extracts the mutable slice containing the entire bytes.
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        // SAFETY: getting mutable slice can not make the bytes empty
+        unsafe { self.as_mut_bytes_mut() }
+    }
+
+    /// Returns the contained bytes as [`NonEmptyBytes`].
+    #[must_use]
+    pub fn as_non_empty_bytes(&self) -> &NonEmptyBytes {
+        // SAFETY: the bytes are non-empty by construction
+        unsafe { NonEmptyBytes::from_slice_unchecked(self.as_slice()) }
+    }
+
+    /// Returns the contained bytes as mutable [`NonEmptyBytes`].
+    #[must_use]
+    pub fn as_non_empty_mut_bytes(&mut self) -> &mut NonEmptyBytes {
+        // SAFETY: the bytes are non-empty by construction
+        unsafe { NonEmptyBytes::from_mut_slice_unchecked(self.as_mut_slice()) }
+    }
+
+    /// Converts [`Self`] into [`NonEmptySharedBytes`].
+    #[must_use]
+    pub fn freeze(self) -> NonEmptySharedBytes {
+        // SAFETY: the bytes are non-empty by construction
+        unsafe { NonEmptySharedBytes::new_unchecked(self.into_bytes_mut().freeze()) }
+    }
+
+    /// Checks if the bytes are empty. Always returns [`false`].
+    ///
+    /// This method is marked as deprecated since the bytes are never empty.
+    #[must_use]
+    #[deprecated = "these bytes are never empty"]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the length of the bytes as [`Size`].
+    #[must_use]
+    pub fn len(&self) -> Size {
+        let len = self.as_bytes_mut().len();
+
+        // SAFETY: the bytes are non-empty by construction
+        unsafe { Size::new_unchecked(len) }
+    }
+
+    /// Returns the capacity of the bytes as [`Size`].
+    #[must_use]
+    pub fn capacity(&self) -> Size {
+        let capacity = self.as_bytes_mut().capacity();
+
+        // SAFETY: non-empty bytes imply non-zero capacity
+        unsafe { Size::new_unchecked(capacity) }
+    }
+
+    /// Reserves capacity for at least `additional` more bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics on capacity overflow.
+    pub fn reserve(&mut self, additional: usize) {
+        // SAFETY: reserving can not make the bytes empty
+        unsafe {
+            self.as_mut_bytes_mut().reserve(additional);
+        }
+    }
+
+    /// Appends the given slice to the end of the bytes.
+    pub fn extend_from_slice(&mut self, slice: &[u8]) {
+        // SAFETY: extending can not make the bytes empty
+        unsafe {
+            self.as_mut_bytes_mut().extend_from_slice(slice);
+        }
+    }
+
+    /// Splits the bytes into two at the given non-zero index,
+    /// returning the bytes before the index and retaining the rest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than or equal to the length of the bytes.
+    pub fn split_to(&mut self, at: Size) -> Self {
+        self.split_to_checked(at)
+            .expect("split index should be less than the length")
+    }
+
+    /// Similar to [`split_to`], but returns [`None`] instead of panicking
+    /// if `at` is greater than or equal to the length of the bytes.
+    ///
+    /// [`split_to`]: Self::split_to
+    pub fn split_to_checked(&mut self, at: Size) -> Option<Self> {
+        (at < self.len())
+            // SAFETY: splitting at non-zero index less than the length keeps both parts non-empty
+            .then(|| unsafe { Self::new_unchecked(self.inner.split_to(at.get())) })
+    }
+
+    /// Splits the bytes into two at the given non-zero index,
+    /// returning the bytes after the index and retaining the rest.
+    ///
+    /// The index has to be non-zero to guarantee the bytes would remain non-empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided index is out of bounds.
+    pub fn split_off(&mut self, at: Size) -> BytesMut {
+        self.inner.split_off(at.get())
+    }
+
+    /// Shortens the bytes, keeping the first `len` bytes and dropping the rest.
+    pub fn truncate(&mut self, len: Size) {
+        self.inner.truncate(len.get());
+    }
+
+    /// Advances the bytes by `count`, discarding the bytes before it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is greater than or equal to the length of the bytes.
+    pub fn advance(&mut self, count: usize) {
+        self.advance_checked(count)
+            .expect("advance count should be less than the length");
+    }
+
+    /// Similar to [`advance`], but returns [`None`] instead of panicking
+    /// if `count` is greater than or equal to the length of the bytes.
+    ///
+    /// [`advance`]: Self::advance
+    pub fn advance_checked(&mut self, count: usize) -> Option<()> {
+        (count < self.len().get()).then(|| self.inner.advance(count))
+    }
+}
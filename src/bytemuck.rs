@@ -0,0 +1,133 @@
+#[cfg(not(feature = "bytemuck"))]
+compile_error!("expected `bytemuck` to be enabled");
+
+use core::mem::size_of;
+
+use bytemuck::{AnyBitPattern, NoUninit, Pod, PodCastError};
+
+use crate::slice::{NonEmptyBytes, NonEmptySlice};
+
+// NOTE: zero-sized types are rejected at compile time, as viewing non-empty slices of them
+// as bytes would result in empty slices
+
+impl<T: Pod> NonEmptySlice<T> {
+    /// Reinterprets the non-empty slice as [`NonEmptyBytes`].
+    ///
+    /// Fails to compile if `T` is zero-sized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptySlice;
+    ///
+    /// let values = [0x0102_u16.to_be(), 0x0304_u16.to_be()];
+    ///
+    /// let non_empty = NonEmptySlice::from_slice(&values).unwrap();
+    ///
+    /// assert_eq!(non_empty.as_non_empty_bytes().as_slice(), [1, 2, 3, 4]);
+    /// ```
+    #[must_use]
+    pub fn as_non_empty_bytes(&self) -> &NonEmptyBytes {
+        const { assert!(size_of::<T>() != 0, "expected non-zero-sized type") };
+
+        let bytes = bytemuck::cast_slice(self.as_slice());
+
+        // SAFETY: the slice is non-empty and `T` is not zero-sized, so the bytes are non-empty
+        unsafe { NonEmptyBytes::from_slice_unchecked(bytes) }
+    }
+
+    /// Reinterprets the non-empty slice as mutable [`NonEmptyBytes`].
+    ///
+    /// Fails to compile if `T` is zero-sized.
+    pub fn as_non_empty_mut_bytes(&mut self) -> &mut NonEmptyBytes {
+        const { assert!(size_of::<T>() != 0, "expected non-zero-sized type") };
+
+        let bytes = bytemuck::cast_slice_mut(self.as_mut_slice());
+
+        // SAFETY: the slice is non-empty and `T` is not zero-sized, so the bytes are non-empty
+        unsafe { NonEmptyBytes::from_mut_slice_unchecked(bytes) }
+    }
+}
+
+impl<T: NoUninit> NonEmptySlice<T> {
+    /// Safely transmutes the slice to slice of another type, ensuring alignment is kept.
+    ///
+    /// Returns the prefix and suffix as regular slices, along with the middle part
+    /// as [`NonEmptySlice<U>`], or [`None`] if it turns out to be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    ///
+    /// let bytes = NonEmptyBytes::from_slice(&[0; 16]).unwrap();
+    ///
+    /// let (prefix, middle, suffix) = bytes.align_to_non_empty::<u32>();
+    ///
+    /// assert!(middle.is_some());
+    ///
+    /// assert_eq!(prefix.len() + suffix.len(), 16 - middle.map_or(0, |middle| middle.len().get() * 4));
+    /// ```
+    pub fn align_to_non_empty<U: AnyBitPattern>(&self) -> (&[T], Option<&NonEmptySlice<U>>, &[T]) {
+        let (prefix, middle, suffix) = bytemuck::pod_align_to(self.as_slice());
+
+        (prefix, NonEmptySlice::from_slice(middle), suffix)
+    }
+}
+
+impl<T: Pod> NonEmptySlice<T> {
+    /// Safely transmutes the mutable slice to mutable slice of another type,
+    /// ensuring alignment is kept.
+    ///
+    /// Returns the prefix and suffix as regular slices, along with the middle part
+    /// as mutable [`NonEmptySlice<U>`], or [`None`] if it turns out to be empty.
+    pub fn align_to_non_empty_mut<U: Pod>(
+        &mut self,
+    ) -> (&mut [T], Option<&mut NonEmptySlice<U>>, &mut [T]) {
+        let (prefix, middle, suffix) = bytemuck::pod_align_to_mut(self.as_mut_slice());
+
+        (prefix, NonEmptySlice::from_mut_slice(middle), suffix)
+    }
+}
+
+impl NonEmptyBytes {
+    /// Reinterprets the non-empty bytes as [`NonEmptySlice<T>`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PodCastError`] if the bytes are not aligned for `T`,
+    /// their length is not divisible by the size of `T`, or `T` is zero-sized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    ///
+    /// let bytes = NonEmptyBytes::from_slice(&[1, 2, 3, 4]).unwrap();
+    ///
+    /// let values = bytes.cast::<[u8; 2]>().unwrap();
+    ///
+    /// assert_eq!(values.as_slice(), [[1, 2], [3, 4]]);
+    ///
+    /// assert!(bytes.cast::<[u8; 3]>().is_err());
+    /// ```
+    pub fn cast<T: Pod>(&self) -> Result<&NonEmptySlice<T>, PodCastError> {
+        let slice = bytemuck::try_cast_slice(self.as_slice())?;
+
+        // SAFETY: casting non-empty bytes either fails or yields non-empty slices
+        Ok(unsafe { NonEmptySlice::from_slice_unchecked(slice) })
+    }
+
+    /// Reinterprets the non-empty bytes as mutable [`NonEmptySlice<T>`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PodCastError`] if the bytes are not aligned for `T`,
+    /// their length is not divisible by the size of `T`, or `T` is zero-sized.
+    pub fn cast_mut<T: Pod>(&mut self) -> Result<&mut NonEmptySlice<T>, PodCastError> {
+        let slice = bytemuck::try_cast_slice_mut(self.as_mut_slice())?;
+
+        // SAFETY: casting non-empty bytes either fails or yields non-empty slices
+        Ok(unsafe { NonEmptySlice::from_mut_slice_unchecked(slice) })
+    }
+}
@@ -7,13 +7,70 @@ compile_error!("expected either `std` or `alloc` to be enabled");
 use std::borrow::Cow;
 
 #[cfg(all(not(feature = "std"), feature = "alloc"))]
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, vec::Vec};
 
-use crate::{boxed::NonEmptyBoxedSlice, slice::NonEmptySlice, vec::NonEmptyVec};
+use crate::{
+    boxed::NonEmptyBoxedSlice,
+    slice::{EmptySlice, NonEmptySlice},
+    vec::{EmptyVec, NonEmptyVec},
+};
 
 /// Represents non-empty clone-on-write slices, [`Cow<'a, NonEmptySlice<T>>`](Cow).
+///
+/// Since this is an alias of [`Cow`], the owned value is [`NonEmptyVec<T>`],
+/// meaning [`Cow::to_mut`] returns [`&mut NonEmptyVec<T>`](NonEmptyVec).
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_slice::cow::{self, NonEmptyCowSlice};
+///
+/// let mut non_empty = cow::try_from_slice(&[1, 2, 3]).unwrap();
+///
+/// assert!(cow::is_borrowed(&non_empty));
+///
+/// non_empty.to_mut().push(4);
+///
+/// assert!(cow::is_owned(&non_empty));
+///
+/// assert!(cow::try_from_slice::<i32>(&[]).is_err());
+/// ```
 pub type NonEmptyCowSlice<'a, T> = Cow<'a, NonEmptySlice<T>>;
 
+// NOTE: `TryFrom` can not be implemented for `NonEmptyCowSlice<'_, T>` from `&[T]` or `Vec<T>`,
+// since neither `Cow` nor the source types are local, therefore functions are provided instead
+
+/// Constructs borrowed [`NonEmptyCowSlice<'_, T>`] from the given slice, provided it is non-empty.
+///
+/// # Errors
+///
+/// Returns [`EmptySlice`] if the slice is empty.
+pub const fn try_from_slice<T: Clone>(slice: &[T]) -> Result<NonEmptyCowSlice<'_, T>, EmptySlice> {
+    match NonEmptySlice::try_from_slice(slice) {
+        Ok(non_empty) => Ok(Cow::Borrowed(non_empty)),
+        Err(error) => Err(error),
+    }
+}
+
+/// Constructs owned [`NonEmptyCowSlice<'_, T>`] from the given vector, provided it is non-empty.
+///
+/// # Errors
+///
+/// Returns [`EmptyVec<T>`] if the vector is empty.
+pub fn try_from_vec<'a, T: Clone>(vec: Vec<T>) -> Result<NonEmptyCowSlice<'a, T>, EmptyVec<T>> {
+    NonEmptyVec::new(vec).map(Cow::Owned)
+}
+
+/// Checks whether the given [`NonEmptyCowSlice<'_, T>`] is borrowed.
+pub const fn is_borrowed<T: Clone>(non_empty: &NonEmptyCowSlice<'_, T>) -> bool {
+    matches!(non_empty, Cow::Borrowed(_))
+}
+
+/// Checks whether the given [`NonEmptyCowSlice<'_, T>`] is owned.
+pub const fn is_owned<T: Clone>(non_empty: &NonEmptyCowSlice<'_, T>) -> bool {
+    !is_borrowed(non_empty)
+}
+
 impl<T: Clone> From<NonEmptyCowSlice<'_, T>> for NonEmptyVec<T> {
     fn from(non_empty: NonEmptyCowSlice<'_, T>) -> Self {
         non_empty.into_owned()
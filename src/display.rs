@@ -0,0 +1,118 @@
+//! Displaying non-empty slices with separators.
+
+use core::fmt;
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::string::{String, ToString};
+
+use crate::slice::NonEmptySlice;
+
+/// Represents adapters displaying items of non-empty slices joined by the given separator.
+///
+/// Since the slice is non-empty, the first item is written as is, and every following one
+/// is preceded by the separator, so there are no trailing separators.
+///
+/// Formatting flags are forwarded to each item, so that `{:>4}`, `{:.2}` and the like
+/// apply to the items individually.
+///
+/// This `struct` is created by the [`display_with`] method on [`NonEmptySlice<T>`].
+///
+/// [`display_with`]: NonEmptySlice::display_with
+pub struct DisplayWith<'a, 's, T> {
+    slice: &'a NonEmptySlice<T>,
+    separator: &'s str,
+}
+
+impl<'a, 's, T> DisplayWith<'a, 's, T> {
+    /// Constructs [`Self`].
+    #[must_use]
+    pub const fn new(slice: &'a NonEmptySlice<T>, separator: &'s str) -> Self {
+        Self { slice, separator }
+    }
+
+    /// Returns the underlying slice.
+    #[must_use]
+    pub const fn get(&self) -> &'a NonEmptySlice<T> {
+        self.slice
+    }
+
+    /// Returns the separator.
+    #[must_use]
+    pub const fn separator(&self) -> &'s str {
+        self.separator
+    }
+}
+
+impl<T> Clone for DisplayWith<'_, '_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for DisplayWith<'_, '_, T> {}
+
+impl<T: fmt::Display> fmt::Display for DisplayWith<'_, '_, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (first, rest) = self.slice.split_first();
+
+        first.fmt(formatter)?;
+
+        for item in rest {
+            formatter.write_str(self.separator)?;
+
+            item.fmt(formatter)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: fmt::Display> fmt::Debug for DisplayWith<'_, '_, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, formatter)
+    }
+}
+
+impl<T> NonEmptySlice<T> {
+    /// Returns the adapter displaying the items joined by the given separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3]);
+    ///
+    /// assert_eq!(non_empty.display_with(", ").to_string(), "1, 2, 3");
+    ///
+    /// assert_eq!(format!("{:02}", non_empty.display_with(":")), "01:02:03");
+    /// ```
+    #[must_use]
+    pub const fn display_with<'s>(&self, separator: &'s str) -> DisplayWith<'_, 's, T> {
+        DisplayWith::new(self, separator)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: fmt::Display> NonEmptySlice<T> {
+    /// Joins the items into [`String`], placing the given separator between them.
+    ///
+    /// This is equivalent to calling [`to_string`] on the result of [`display_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[13, 42, 69]);
+    ///
+    /// assert_eq!(non_empty.join_to_string(" + "), "13 + 42 + 69");
+    /// ```
+    ///
+    /// [`to_string`]: ToString::to_string
+    /// [`display_with`]: Self::display_with
+    #[must_use]
+    pub fn join_to_string(&self, separator: &str) -> String {
+        self.display_with(separator).to_string()
+    }
+}
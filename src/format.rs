@@ -2,9 +2,49 @@ pub mod import {
     pub use core::fmt;
 }
 
+use core::fmt;
+
+/// Displays the message, prefixed with the context if it is present.
+pub struct Contextual {
+    context: Option<&'static str>,
+    message: &'static str,
+}
+
+impl Contextual {
+    pub const fn new(context: Option<&'static str>, message: &'static str) -> Self {
+        Self { context, message }
+    }
+}
+
+impl fmt::Display for Contextual {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(context) = self.context {
+            write!(formatter, "{context}: ")?;
+        }
+
+        formatter.write_str(self.message)
+    }
+}
+
 macro_rules! debug {
+    (str $name: ident, $field: ident) => {
+        impl $crate::format::import::fmt::Debug for $name {
+            fn fmt(
+                &self,
+                formatter: &mut $crate::format::import::fmt::Formatter<'_>,
+            ) -> $crate::format::import::fmt::Result {
+                formatter
+                    .debug_struct(stringify!($name))
+                    .field(stringify!($field), &"")
+                    .finish()
+            }
+        }
+    };
     ($name: ident, $field: ident) => {
-        impl<T> $crate::format::import::fmt::Debug for $name<T> {
+        $crate::format::debug!($name<T>, $field);
+    };
+    ($name: ident<$generic: ident $(: $bound: path)?>, $field: ident) => {
+        impl<$generic $(: $bound)?> $crate::format::import::fmt::Debug for $name<$generic> {
             fn fmt(
                 &self,
                 formatter: &mut $crate::format::import::fmt::Formatter<'_>,
@@ -0,0 +1,357 @@
+//! Hexadecimal encoding and decoding of non-empty bytes.
+
+use core::fmt::{self, Write};
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::vec::Vec;
+
+use thiserror::Error;
+
+use crate::{slice::NonEmptyBytes, str::EmptyStr};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::vec::NonEmptyByteVec;
+
+/// The error message used when the number of hexadecimal digits is odd.
+pub const ODD_LENGTH: &str = "the number of hex digits is odd";
+
+/// Represents errors returned when decoding hexadecimal strings into non-empty bytes.
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(miette::Diagnostic))]
+pub enum FromHexError {
+    /// The string is empty.
+    #[error(transparent)]
+    #[cfg_attr(feature = "diagnostics", diagnostic(transparent))]
+    Empty(#[from] EmptyStr),
+    /// The number of hexadecimal digits is odd.
+    #[error("{ODD_LENGTH}")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(non_empty_slice::hex::odd_length),
+            help("make sure each byte is encoded with two hex digits")
+        )
+    )]
+    OddLength,
+    /// The string contains invalid hexadecimal digit.
+    #[error("invalid hex digit `{character}` at index {index}")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(non_empty_slice::hex::invalid_digit),
+            help("make sure the string only contains hex digits")
+        )
+    )]
+    InvalidDigit {
+        /// The invalid character.
+        character: char,
+        /// The byte index of the invalid character.
+        index: usize,
+    },
+}
+
+const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+const UPPER_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+fn write_hex(
+    formatter: &mut fmt::Formatter<'_>,
+    bytes: &NonEmptyBytes,
+    digits: &[u8; 16],
+) -> fmt::Result {
+    for &byte in bytes {
+        let pair = [
+            digits[usize::from(byte >> 4)],
+            digits[usize::from(byte & 0x0F)],
+        ];
+
+        // SAFETY: hex digits are always valid UTF-8
+        formatter.write_str(unsafe { str::from_utf8_unchecked(&pair) })?;
+    }
+
+    Ok(())
+}
+
+fn write_binary(formatter: &mut fmt::Formatter<'_>, bytes: &NonEmptyBytes) -> fmt::Result {
+    for byte in bytes {
+        write!(formatter, "{byte:08b}")?;
+    }
+
+    Ok(())
+}
+
+// NOTE: `Formatter::pad_integral` would require the digits to be written into the buffer first,
+// so the padding is applied manually here, following the same rules as for integers
+
+fn pad(
+    formatter: &mut fmt::Formatter<'_>,
+    prefix: &str,
+    len: usize,
+    write: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    let prefix = if formatter.alternate() { prefix } else { "" };
+
+    let padding = formatter
+        .width()
+        .unwrap_or_default()
+        .saturating_sub(prefix.len() + len);
+
+    if formatter.sign_aware_zero_pad() {
+        formatter.write_str(prefix)?;
+
+        for _ in 0..padding {
+            formatter.write_char('0')?;
+        }
+
+        return write(formatter);
+    }
+
+    let (before, after) = match formatter.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+
+    let fill = formatter.fill();
+
+    for _ in 0..before {
+        formatter.write_char(fill)?;
+    }
+
+    formatter.write_str(prefix)?;
+
+    write(formatter)?;
+
+    for _ in 0..after {
+        formatter.write_char(fill)?;
+    }
+
+    Ok(())
+}
+
+/// Formats the bytes as contiguous lowercase hexadecimal string.
+///
+/// Width, fill, alignment, zero-padding and alternate (`0x` prefix) flags are honored.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_slice::NonEmptyBytes;
+///
+/// let bytes = NonEmptyBytes::from_slice(b"\xDE\xAD").unwrap();
+///
+/// assert_eq!(format!("{bytes:x}"), "dead");
+/// assert_eq!(format!("{bytes:#x}"), "0xdead");
+/// assert_eq!(format!("{bytes:>8x}"), "    dead");
+/// assert_eq!(format!("{bytes:#08x}"), "0x00dead");
+/// ```
+impl fmt::LowerHex for NonEmptyBytes {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(formatter, "0x", self.len().get() * 2, |formatter| {
+            write_hex(formatter, self, DIGITS)
+        })
+    }
+}
+
+/// Formats the bytes as contiguous uppercase hexadecimal string.
+///
+/// Width, fill, alignment, zero-padding and alternate (`0x` prefix) flags are honored.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_slice::NonEmptyBytes;
+///
+/// let bytes = NonEmptyBytes::from_slice(b"\xBE\xEF").unwrap();
+///
+/// assert_eq!(format!("{bytes:X}"), "BEEF");
+/// assert_eq!(format!("{bytes:*<6X}"), "BEEF**");
+/// ```
+impl fmt::UpperHex for NonEmptyBytes {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(formatter, "0x", self.len().get() * 2, |formatter| {
+            write_hex(formatter, self, UPPER_DIGITS)
+        })
+    }
+}
+
+/// Formats the bytes as contiguous binary string, using eight digits per byte.
+///
+/// Width, fill, alignment, zero-padding and alternate (`0b` prefix) flags are honored.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_slice::NonEmptyBytes;
+///
+/// let bytes = NonEmptyBytes::from_slice(&[1, 128]).unwrap();
+///
+/// assert_eq!(format!("{bytes:#b}"), "0b0000000110000000");
+/// ```
+impl fmt::Binary for NonEmptyBytes {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad(formatter, "0b", self.len().get() * 8, |formatter| {
+            write_binary(formatter, self)
+        })
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl fmt::LowerHex for NonEmptyByteVec {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self.as_non_empty_slice(), formatter)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl fmt::UpperHex for NonEmptyByteVec {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(self.as_non_empty_slice(), formatter)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl fmt::Binary for NonEmptyByteVec {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Binary::fmt(self.as_non_empty_slice(), formatter)
+    }
+}
+
+/// Represents adapters displaying non-empty bytes as lowercase hexadecimal strings.
+///
+/// Both [`Display`] and [`Debug`] are implemented, writing two digits per byte.
+///
+/// This `struct` is created by the [`hex`] method on [`NonEmptyBytes`].
+///
+/// [`Display`]: fmt::Display
+/// [`Debug`]: fmt::Debug
+/// [`hex`]: NonEmptyBytes::hex
+#[derive(Clone, Copy)]
+pub struct Hex<'a> {
+    bytes: &'a NonEmptyBytes,
+}
+
+impl<'a> Hex<'a> {
+    /// Constructs [`Self`].
+    #[must_use]
+    pub const fn new(bytes: &'a NonEmptyBytes) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the underlying bytes.
+    #[must_use]
+    pub const fn get(self) -> &'a NonEmptyBytes {
+        self.bytes
+    }
+}
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(formatter, self.bytes, DIGITS)
+    }
+}
+
+impl fmt::Debug for Hex<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, formatter)
+    }
+}
+
+impl NonEmptyBytes {
+    /// Returns the adapter displaying the bytes as lowercase hexadecimal string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    ///
+    /// let bytes = NonEmptyBytes::from_slice(b"\xDE\xAD\xBE\xEF").unwrap();
+    ///
+    /// assert_eq!(bytes.hex().to_string(), "deadbeef");
+    /// ```
+    #[must_use]
+    pub const fn hex(&self) -> Hex<'_> {
+        Hex::new(self)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+const fn decode_digit(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl NonEmptyByteVec {
+    /// Decodes the given hexadecimal string into [`Self`].
+    ///
+    /// Both lowercase and uppercase digits are accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromHexError`] if the string is empty, contains odd number of digits,
+    /// or contains invalid digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::{NonEmptyByteVec, hex::FromHexError};
+    ///
+    /// let bytes = NonEmptyByteVec::from_hex("DEADbeef").unwrap();
+    ///
+    /// assert_eq!(bytes, [0xDE, 0xAD, 0xBE, 0xEF]);
+    ///
+    /// assert!(matches!(
+    ///     NonEmptyByteVec::from_hex(""),
+    ///     Err(FromHexError::Empty(_)),
+    /// ));
+    ///
+    /// assert!(matches!(
+    ///     NonEmptyByteVec::from_hex("abc"),
+    ///     Err(FromHexError::OddLength),
+    /// ));
+    ///
+    /// assert!(matches!(
+    ///     NonEmptyByteVec::from_hex("nekit!"),
+    ///     Err(FromHexError::InvalidDigit { character: 'n', index: 0 }),
+    /// ));
+    /// ```
+    pub fn from_hex(string: &str) -> Result<Self, FromHexError> {
+        if string.is_empty() {
+            return Err(EmptyStr.into());
+        }
+
+        let digits = string.as_bytes();
+
+        if !digits.len().is_multiple_of(2) {
+            return Err(FromHexError::OddLength);
+        }
+
+        let invalid = |index: usize| {
+            // SAFETY: all preceding bytes are ASCII digits, so the index is always
+            // within the string and on the character boundary
+            let character = unsafe { string[index..].chars().next().unwrap_unchecked() };
+
+            FromHexError::InvalidDigit { character, index }
+        };
+
+        let mut bytes = Vec::with_capacity(digits.len() / 2);
+
+        for (index, pair) in digits.chunks_exact(2).enumerate() {
+            let index = index * 2;
+
+            let high = decode_digit(pair[0]).ok_or_else(|| invalid(index))?;
+            let low = decode_digit(pair[1]).ok_or_else(|| invalid(index + 1))?;
+
+            bytes.push(high << 4 | low);
+        }
+
+        // SAFETY: the string is non-empty and contains even number of digits,
+        // so there is at least one byte
+        Ok(unsafe { Self::new_unchecked(bytes) })
+    }
+}
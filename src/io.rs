@@ -1,15 +1,20 @@
+//! Non-empty I/O.
+
 #[cfg(not(feature = "std"))]
 compile_error!("expected `std` to be enabled");
 
 use core::fmt;
 
-use std::io::{IoSlice, Result, Write};
+use std::io::{BufRead, Cursor, Error, ErrorKind, IoSlice, Read, Result, Seek, SeekFrom, Write};
 
 use crate::{slice::NonEmptyBytes, vec::NonEmptyByteVec};
 
 type Bytes = [u8];
 type ByteSlices<'a> = [IoSlice<'a>];
 
+// NOTE: `Read` and `BufRead` are not implemented for `&NonEmptyBytes`, since reading advances
+// the slice, which would eventually make it empty; use `as_slice` or `NonEmptyCursor` instead
+
 impl Write for &mut NonEmptyBytes {
     fn write(&mut self, buffer: &Bytes) -> Result<usize> {
         self.as_mut_slice().write(buffer)
@@ -58,3 +63,204 @@ impl Write for NonEmptyByteVec {
         unsafe { self.as_mut_vec().flush() }
     }
 }
+
+/// The error message used when seeking to negative or overflowing positions.
+pub const INVALID_SEEK: &str = "invalid seek to a negative or overflowing position";
+
+/// Represents cursors over non-empty bytes, similar to [`Cursor<T>`].
+///
+/// Unlike [`Cursor<T>`], the non-empty knowledge is retained, both for the underlying bytes
+/// and for the remaining bytes, see [`remaining_non_empty`].
+///
+/// [`Read`], [`BufRead`] and [`Seek`] are implemented whenever `T` implements
+/// [`AsRef<[u8]>`](AsRef), while [`Write`] is implemented for
+/// [`&mut NonEmptyBytes`](NonEmptyBytes) and [`NonEmptyByteVec`].
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{Read, Seek, SeekFrom, Write};
+///
+/// use non_empty_slice::{io::NonEmptyCursor, non_empty_vec};
+///
+/// let mut cursor = NonEmptyCursor::new(non_empty_vec![1, 2, 3]);
+///
+/// let mut buffer = [0; 2];
+///
+/// cursor.read_exact(&mut buffer).unwrap();
+///
+/// assert_eq!(buffer, [1, 2]);
+///
+/// assert_eq!(cursor.remaining_non_empty().unwrap(), &[3]);
+///
+/// cursor.write_all(&[4, 5]).unwrap();
+///
+/// assert!(cursor.remaining_non_empty().is_none());
+///
+/// cursor.seek(SeekFrom::Start(0)).unwrap();
+///
+/// assert_eq!(cursor.remaining_non_empty().unwrap(), &[1, 2, 4, 5]);
+/// ```
+///
+/// [`remaining_non_empty`]: Self::remaining_non_empty
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NonEmptyCursor<T> {
+    inner: T,
+    position: u64,
+}
+
+impl<T> NonEmptyCursor<T> {
+    /// Constructs [`Self`] wrapping the given value, starting at the beginning.
+    pub const fn new(inner: T) -> Self {
+        Self { inner, position: 0 }
+    }
+
+    /// Consumes [`Self`], returning the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Returns the reference to the wrapped value.
+    pub const fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the mutable reference to the wrapped value.
+    ///
+    /// Note that the position is not changed, even if the length of the value changes.
+    pub const fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Returns the current position of the cursor.
+    pub const fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Sets the position of the cursor.
+    ///
+    /// Note that the position is allowed to go past the end of the wrapped value.
+    pub const fn set_position(&mut self, position: u64) {
+        self.position = position;
+    }
+}
+
+impl<T: AsRef<Bytes>> NonEmptyCursor<T> {
+    /// Returns the remaining bytes, starting at the current position.
+    ///
+    /// If the position is past the end, the returned slice is empty.
+    pub fn remaining_slice(&self) -> &Bytes {
+        let bytes = self.inner.as_ref();
+
+        let start = usize::try_from(self.position)
+            .map_or(bytes.len(), |position| position.min(bytes.len()));
+
+        &bytes[start..]
+    }
+
+    /// Returns the remaining bytes, starting at the current position,
+    /// as [`NonEmptyBytes`], provided there are any.
+    pub fn remaining_non_empty(&self) -> Option<&NonEmptyBytes> {
+        NonEmptyBytes::from_slice(self.remaining_slice())
+    }
+
+    /// Checks if there are no remaining bytes.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining_slice().is_empty()
+    }
+
+    fn advance(&mut self, count: usize) {
+        self.position += count as u64;
+    }
+}
+
+impl<T: AsRef<Bytes>> Read for NonEmptyCursor<T> {
+    fn read(&mut self, buffer: &mut Bytes) -> Result<usize> {
+        let count = self.remaining_slice().read(buffer)?;
+
+        self.advance(count);
+
+        Ok(count)
+    }
+
+    fn read_exact(&mut self, buffer: &mut Bytes) -> Result<()> {
+        self.remaining_slice().read_exact(buffer)?;
+
+        self.advance(buffer.len());
+
+        Ok(())
+    }
+}
+
+impl<T: AsRef<Bytes>> BufRead for NonEmptyCursor<T> {
+    fn fill_buf(&mut self) -> Result<&Bytes> {
+        Ok(self.remaining_slice())
+    }
+
+    fn consume(&mut self, count: usize) {
+        self.advance(count);
+    }
+}
+
+impl<T: AsRef<Bytes>> Seek for NonEmptyCursor<T> {
+    fn seek(&mut self, seek: SeekFrom) -> Result<u64> {
+        let (base, offset) = match seek {
+            SeekFrom::Start(position) => {
+                self.position = position;
+
+                return Ok(position);
+            }
+            SeekFrom::End(offset) => (self.inner.as_ref().len() as u64, offset),
+            SeekFrom::Current(offset) => (self.position, offset),
+        };
+
+        let position = base
+            .checked_add_signed(offset)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, INVALID_SEEK))?;
+
+        self.position = position;
+
+        Ok(position)
+    }
+
+    fn stream_position(&mut self) -> Result<u64> {
+        Ok(self.position)
+    }
+}
+
+impl Write for NonEmptyCursor<&mut NonEmptyBytes> {
+    fn write(&mut self, buffer: &Bytes) -> Result<usize> {
+        let mut cursor = Cursor::new(self.inner.as_mut_slice());
+
+        cursor.set_position(self.position);
+
+        let count = cursor.write(buffer)?;
+
+        self.position = cursor.position();
+
+        Ok(count)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Write for NonEmptyCursor<NonEmptyByteVec> {
+    fn write(&mut self, buffer: &Bytes) -> Result<usize> {
+        // SAFETY: writing can not make the vector empty
+        let mut cursor = Cursor::new(unsafe { self.inner.as_mut_vec() });
+
+        cursor.set_position(self.position);
+
+        let count = cursor.write(buffer)?;
+
+        self.position = cursor.position();
+
+        Ok(count)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -8,7 +8,7 @@ use alloc::vec::IntoIter;
 
 use core::{
     fmt,
-    iter::Map,
+    iter::{Enumerate, FusedIterator, Map, Rev, Zip},
     slice::{self, Iter, IterMut},
 };
 
@@ -16,7 +16,13 @@ use non_zero_size::Size;
 
 use non_empty_iter::{NonEmptyAdapter, NonEmptyIterator};
 
-use crate::slice::{NonEmptyBytes, NonEmptySlice};
+use crate::{
+    search,
+    slice::{NonEmptyBytes, NonEmptySlice},
+};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::vec::NonEmptyVec;
 
 /// Represents non-empty by-value iterators.
 #[cfg(any(feature = "std", feature = "alloc"))]
@@ -28,6 +34,134 @@ pub type NonEmptyIter<'a, T> = NonEmptyAdapter<Iter<'a, T>>;
 /// Represents non-empty by-mutable-reference iterators.
 pub type NonEmptyIterMut<'a, T> = NonEmptyAdapter<IterMut<'a, T>>;
 
+/// Represents non-empty iterators over items of non-empty slices along with their indices.
+pub type NonEmptyEnumerate<'a, T> = NonEmptyAdapter<Enumerate<Iter<'a, T>>>;
+
+/// Represents non-empty iterators over mutable items of non-empty slices
+/// along with their indices.
+pub type NonEmptyEnumerateMut<'a, T> = NonEmptyAdapter<Enumerate<IterMut<'a, T>>>;
+
+/// Represents non-empty iterators over pairs of items of two non-empty slices.
+pub type NonEmptyZip<'a, A, B> = NonEmptyAdapter<Zip<Iter<'a, A>, Iter<'a, B>>>;
+
+/// Returns non-empty iterator over pairs of items of two non-empty slices.
+///
+/// The length of the iterator is the minimum of the lengths of the slices,
+/// which is always non-zero.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_slice::{iter::zip, non_empty_slice};
+///
+/// let numbers = non_empty_slice!(&[1, 2, 3]);
+/// let names = non_empty_slice!(&["one", "two"]);
+///
+/// let pairs: Vec<_> = zip(numbers, names).into_iter().collect();
+///
+/// assert_eq!(pairs, [(&1, &"one"), (&2, &"two")]);
+/// ```
+pub fn zip<'a, A, B>(
+    left: &'a NonEmptySlice<A>,
+    right: &'a NonEmptySlice<B>,
+) -> NonEmptyZip<'a, A, B> {
+    // SAFETY: zipping two non-empty slices yields at least one pair
+    unsafe { NonEmptyAdapter::new(left.iter().zip(right.iter())) }
+}
+
+// NOTE: the types below implement `IntoIterator` rather than `Iterator`, since advancing them
+// would break the non-emptiness guarantee that `NonEmptyIterator` relies on; the iterators
+// they turn into implement `DoubleEndedIterator` and `ExactSizeIterator` where possible
+
+// NOTE: inherent `len` returning `Size` is provided only where the count is guaranteed
+// to be non-zero; `ChunksExact`, `RChunksExact` and `Windows` (along with the mutable
+// counterparts) are empty whenever the size exceeds the length of the slice
+
+// NOTE: the types that only share the underlying data are `Clone` (and `Copy` when possible),
+// and implement `IntoIterator` for references, so that they can be traversed multiple times;
+// the mutable ones can not do so, as that would alias mutable references
+
+// NOTE: references to `ChunksExact`, `RChunksExact` and `Windows` do not implement
+// `NonEmptyIterator`, since these are empty whenever the size exceeds the length of the slice
+
+// NOTE: the adapters below preserve non-emptiness, so they are provided as inherent methods
+// forwarding to `NonEmptyIterator`, which avoids having to import the trait; they are omitted
+// for `ChunksExact`, `RChunksExact` and `Windows` (along with the mutable counterparts),
+// since these are empty whenever the size exceeds the length of the slice
+
+macro_rules! non_empty_adapters {
+    ($({$($generics:tt)*} $type:ty $({$($bounds:tt)*})?),+ $(,)?) => {
+        $(
+            impl<$($generics)*> $type $(where $($bounds)*)? {
+                /// Maps the items using the given function, preserving non-emptiness.
+                ///
+                /// See [`NonEmptyIterator::map`] for more information.
+                pub fn map<U, F: FnMut(<Self as IntoIterator>::Item) -> U>(
+                    self,
+                    function: F,
+                ) -> non_empty_iter::Map<Self, F> {
+                    NonEmptyIterator::map(self, function)
+                }
+
+                /// Reverses the direction of iteration, preserving non-emptiness.
+                ///
+                /// See [`NonEmptyIterator::rev`] for more information.
+                pub fn rev(self) -> non_empty_iter::Rev<Self>
+                where
+                    <Self as IntoIterator>::IntoIter: DoubleEndedIterator,
+                {
+                    NonEmptyIterator::rev(self)
+                }
+
+                /// Takes only the first given number of items, preserving non-emptiness.
+                ///
+                /// See [`NonEmptyIterator::take`] for more information.
+                pub fn take(self, count: Size) -> non_empty_iter::Take<Self> {
+                    NonEmptyIterator::take(self, count)
+                }
+
+                /// Steps by the given amount, preserving non-emptiness.
+                ///
+                /// See [`NonEmptyIterator::step_by`] for more information.
+                pub fn step_by(self, step: Size) -> non_empty_iter::StepBy<Self> {
+                    NonEmptyIterator::step_by(self, step)
+                }
+            }
+        )+
+    };
+}
+
+// NOTE: the iterators yielding shared non-empty slices that partition the original slice
+// can be flattened back into non-empty vectors, since the first slice is always present;
+// the ones depending on the size not exceeding the length (such as `ChunksExact`) may be empty,
+// and flattening overlapping windows would duplicate items, so they are deliberately omitted
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+macro_rules! collect_into_non_empty_vec {
+    ($({$($generics:tt)*} $type:ty),+ $(,)?) => {
+        $(
+            impl<$($generics)*> $type {
+                /// Flattens the produced non-empty slices into [`NonEmptyVec<T>`],
+                /// cloning the items.
+                pub fn collect_into_non_empty_vec(self) -> NonEmptyVec<T>
+                where
+                    T: Clone,
+                {
+                    let (first, rest) = self.consume();
+
+                    let mut output = first.to_non_empty_vec();
+
+                    for item in rest {
+                        output.extend_from(item);
+                    }
+
+                    output
+                }
+            }
+        )+
+    };
+}
+
 /// Represents functions mapping chunks to non-empty slices.
 ///
 /// This is mostly an implementation detail, though it can be useful in case
@@ -45,6 +179,61 @@ pub type NonEmptyMutSliceFn<'a, T> = fn(&'a mut [T]) -> &'a mut NonEmptySlice<T>
 ///
 /// This `struct` is created by the [`chunks`] method on [`NonEmptySlice<T>`].
 ///
+/// # Examples
+///
+/// ```
+/// use non_empty_slice::non_empty_slice;
+/// use non_zero_size::const_size;
+///
+/// let non_empty = non_empty_slice!(&[1, 2, 3, 4, 5]);
+///
+/// let chunks = non_empty.chunks(const_size!(2));
+///
+/// assert_eq!(chunks.len().get(), 3);
+///
+/// let mut iterator = chunks.into_iter();
+///
+/// assert_eq!(iterator.len(), 3);
+///
+/// assert_eq!(iterator.next_back().unwrap().as_slice(), [5]);
+/// ```
+///
+/// Traversing the same chunks multiple times:
+///
+/// ```
+/// use non_empty_slice::non_empty_slice;
+/// use non_zero_size::const_size;
+///
+/// let non_empty = non_empty_slice!(&[1, 2, 3, 4, 5]);
+///
+/// let chunks = non_empty.chunks(const_size!(2));
+///
+/// let firsts: Vec<_> = (&chunks).into_iter().map(|chunk| *chunk.first()).collect();
+/// let lasts: Vec<_> = (&chunks).into_iter().map(|chunk| *chunk.last()).collect();
+///
+/// assert_eq!(firsts, [1, 3, 5]);
+/// assert_eq!(lasts, [2, 4, 5]);
+/// ```
+///
+/// Adapting chunks while preserving non-emptiness:
+///
+/// ```
+/// use non_empty_iter::NonEmptyIterator;
+/// use non_empty_slice::{NonEmptyVec, non_empty_slice};
+/// use non_zero_size::const_size;
+///
+/// let non_empty = non_empty_slice!(&[1, 2, 3, 4, 5]);
+///
+/// let sums: NonEmptyVec<i32> = non_empty
+///     .chunks(const_size!(2))
+///     .rev()
+///     .take(const_size!(2))
+///     .map(|chunk| chunk.iter().sum())
+///     .collect_non_empty();
+///
+/// assert_eq!(sums, [5, 7]);
+/// ```
+///
 /// [`chunks`]: NonEmptySlice::chunks
 #[derive(Debug)]
 pub struct Chunks<'a, T> {
@@ -57,6 +246,24 @@ impl<'a, T> Chunks<'a, T> {
     pub const fn new(slice: &'a NonEmptySlice<T>, size: Size) -> Self {
         Self { slice, size }
     }
+
+    /// Returns the number of chunks as [`Size`].
+    #[must_use]
+    pub const fn len(&self) -> Size {
+        let len = self.slice.len().get().div_ceil(self.size.get());
+
+        // SAFETY: non-empty slice always has at least one chunk
+        unsafe { Size::new_unchecked(len) }
+    }
+
+    /// Checks if there are no chunks. Always returns [`false`].
+    ///
+    /// This method is marked as deprecated since there is always at least one chunk.
+    #[must_use]
+    #[deprecated = "there is always at least one chunk"]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
 }
 
 impl<'a, T> IntoIterator for Chunks<'a, T> {
@@ -75,6 +282,26 @@ impl<'a, T> IntoIterator for Chunks<'a, T> {
 
 unsafe impl<T> NonEmptyIterator for Chunks<'_, T> {}
 
+impl<T> Clone for Chunks<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Chunks<'_, T> {}
+
+impl<'a, T> IntoIterator for &Chunks<'a, T> {
+    type Item = <Chunks<'a, T> as IntoIterator>::Item;
+
+    type IntoIter = <Chunks<'a, T> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
+unsafe impl<T> NonEmptyIterator for &Chunks<'_, T> {}
+
 /// Represents non-empty iterators over non-empty slices in (non-overlapping) mutable chunks,
 /// starting at the beginning of the non-empty slice.
 ///
@@ -92,6 +319,24 @@ impl<'a, T> ChunksMut<'a, T> {
     pub const fn new(slice: &'a mut NonEmptySlice<T>, size: Size) -> Self {
         Self { slice, size }
     }
+
+    /// Returns the number of chunks as [`Size`].
+    #[must_use]
+    pub const fn len(&self) -> Size {
+        let len = self.slice.len().get().div_ceil(self.size.get());
+
+        // SAFETY: non-empty slice always has at least one chunk
+        unsafe { Size::new_unchecked(len) }
+    }
+
+    /// Checks if there are no chunks. Always returns [`false`].
+    ///
+    /// This method is marked as deprecated since there is always at least one chunk.
+    #[must_use]
+    #[deprecated = "there is always at least one chunk"]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
 }
 
 impl<'a, T> IntoIterator for ChunksMut<'a, T> {
@@ -127,10 +372,48 @@ impl<'a, T> RChunks<'a, T> {
     pub const fn new(slice: &'a NonEmptySlice<T>, size: Size) -> Self {
         Self { slice, size }
     }
+
+    /// Returns the number of chunks as [`Size`].
+    #[must_use]
+    pub const fn len(&self) -> Size {
+        let len = self.slice.len().get().div_ceil(self.size.get());
+
+        // SAFETY: non-empty slice always has at least one chunk
+        unsafe { Size::new_unchecked(len) }
+    }
+
+    /// Checks if there are no chunks. Always returns [`false`].
+    ///
+    /// This method is marked as deprecated since there is always at least one chunk.
+    #[must_use]
+    #[deprecated = "there is always at least one chunk"]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
 }
 
 unsafe impl<T> NonEmptyIterator for RChunks<'_, T> {}
 
+impl<T> Clone for RChunks<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RChunks<'_, T> {}
+
+impl<'a, T> IntoIterator for &RChunks<'a, T> {
+    type Item = <RChunks<'a, T> as IntoIterator>::Item;
+
+    type IntoIter = <RChunks<'a, T> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
+unsafe impl<T> NonEmptyIterator for &RChunks<'_, T> {}
+
 impl<'a, T> IntoIterator for RChunks<'a, T> {
     type Item = &'a NonEmptySlice<T>;
 
@@ -162,6 +445,24 @@ impl<'a, T> RChunksMut<'a, T> {
     pub const fn new(slice: &'a mut NonEmptySlice<T>, size: Size) -> Self {
         Self { slice, size }
     }
+
+    /// Returns the number of chunks as [`Size`].
+    #[must_use]
+    pub const fn len(&self) -> Size {
+        let len = self.slice.len().get().div_ceil(self.size.get());
+
+        // SAFETY: non-empty slice always has at least one chunk
+        unsafe { Size::new_unchecked(len) }
+    }
+
+    /// Checks if there are no chunks. Always returns [`false`].
+    ///
+    /// This method is marked as deprecated since there is always at least one chunk.
+    #[must_use]
+    #[deprecated = "there is always at least one chunk"]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
 }
 
 impl<'a, T> IntoIterator for RChunksMut<'a, T> {
@@ -184,11 +485,25 @@ unsafe impl<T> NonEmptyIterator for RChunksMut<'_, T> {}
 /// starting at the beginning of the non-empty slice.
 ///
 /// When the length of the non-empty slice is not divisible by the chunk size,
-/// the last chunk will be omitted.
+/// the last chunk will be omitted, and can be retrieved via [`remainder`].
 ///
 /// This `struct` is created by the [`chunks_exact`] method on [`NonEmptySlice<T>`].
 ///
+/// # Examples
+///
+/// ```
+/// use non_empty_slice::non_empty_slice;
+/// use non_zero_size::const_size;
+///
+/// let non_empty = non_empty_slice!(&[1, 2, 3, 4, 5]);
+///
+/// let chunks = non_empty.chunks_exact(const_size!(2));
+///
+/// assert_eq!(chunks.remainder(), [5]);
+/// ```
+///
 /// [`chunks_exact`]: NonEmptySlice::chunks_exact
+/// [`remainder`]: Self::remainder
 #[derive(Debug)]
 pub struct ChunksExact<'a, T> {
     slice: &'a NonEmptySlice<T>,
@@ -200,6 +515,16 @@ impl<'a, T> ChunksExact<'a, T> {
     pub const fn new(slice: &'a NonEmptySlice<T>, size: Size) -> Self {
         Self { slice, size }
     }
+
+    /// Returns the remainder of the original slice that is not going to be returned
+    /// by the iterator, containing less than the chunk size items.
+    #[must_use]
+    pub fn remainder(&self) -> &'a [T] {
+        self.slice
+            .as_slice()
+            .chunks_exact(self.size.get())
+            .remainder()
+    }
 }
 
 impl<'a, T> IntoIterator for ChunksExact<'a, T> {
@@ -218,6 +543,24 @@ impl<'a, T> IntoIterator for ChunksExact<'a, T> {
 
 unsafe impl<T> NonEmptyIterator for ChunksExact<'_, T> {}
 
+impl<T> Clone for ChunksExact<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ChunksExact<'_, T> {}
+
+impl<'a, T> IntoIterator for &ChunksExact<'a, T> {
+    type Item = <ChunksExact<'a, T> as IntoIterator>::Item;
+
+    type IntoIter = <ChunksExact<'a, T> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
 /// Represents non-empty iterators over non-empty slices in (non-overlapping) mutable chunks,
 /// starting at the beginning of the non-empty slice.
 ///
@@ -238,6 +581,16 @@ impl<'a, T> ChunksExactMut<'a, T> {
     pub const fn new(slice: &'a mut NonEmptySlice<T>, size: Size) -> Self {
         Self { slice, size }
     }
+
+    /// Returns the remainder of the original slice that is not going to be returned
+    /// by the iterator, containing less than the chunk size items.
+    #[must_use]
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.slice
+            .as_mut_slice()
+            .chunks_exact_mut(self.size.get())
+            .into_remainder()
+    }
 }
 
 impl<'a, T> IntoIterator for ChunksExactMut<'a, T> {
@@ -276,6 +629,16 @@ impl<'a, T> RChunksExact<'a, T> {
     pub const fn new(slice: &'a NonEmptySlice<T>, size: Size) -> Self {
         Self { slice, size }
     }
+
+    /// Returns the remainder of the original slice that is not going to be returned
+    /// by the iterator, containing less than the chunk size items.
+    #[must_use]
+    pub fn remainder(&self) -> &'a [T] {
+        self.slice
+            .as_slice()
+            .rchunks_exact(self.size.get())
+            .remainder()
+    }
 }
 
 impl<'a, T> IntoIterator for RChunksExact<'a, T> {
@@ -294,6 +657,24 @@ impl<'a, T> IntoIterator for RChunksExact<'a, T> {
 
 unsafe impl<T> NonEmptyIterator for RChunksExact<'_, T> {}
 
+impl<T> Clone for RChunksExact<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RChunksExact<'_, T> {}
+
+impl<'a, T> IntoIterator for &RChunksExact<'a, T> {
+    type Item = <RChunksExact<'a, T> as IntoIterator>::Item;
+
+    type IntoIter = <RChunksExact<'a, T> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
 /// Represents non-empty iterators over non-empty slices in (non-overlapping) mutable chunks,
 /// starting at the end of the non-empty slice.
 ///
@@ -314,6 +695,16 @@ impl<'a, T> RChunksExactMut<'a, T> {
     pub const fn new(slice: &'a mut NonEmptySlice<T>, size: Size) -> Self {
         Self { slice, size }
     }
+
+    /// Returns the remainder of the original slice that is not going to be returned
+    /// by the iterator, containing less than the chunk size items.
+    #[must_use]
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.slice
+            .as_mut_slice()
+            .rchunks_exact_mut(self.size.get())
+            .into_remainder()
+    }
 }
 
 impl<'a, T> IntoIterator for RChunksExactMut<'a, T> {
@@ -366,11 +757,283 @@ impl<'a, T> IntoIterator for Windows<'a, T> {
 
 unsafe impl<T> NonEmptyIterator for Windows<'_, T> {}
 
+impl<T> Clone for Windows<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Windows<'_, T> {}
+
+impl<'a, T> IntoIterator for &Windows<'a, T> {
+    type Item = <Windows<'a, T> as IntoIterator>::Item;
+
+    type IntoIter = <Windows<'a, T> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
+/// Represents non-empty iterators over non-empty slices in (overlapping) windows,
+/// starting at the end of the slice.
+///
+/// This `struct` is created by the [`rwindows`] method on [`NonEmptySlice<T>`].
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_slice::{const_size, non_empty_slice};
+///
+/// let non_empty = non_empty_slice!(&[1, 2, 3, 4]);
+///
+/// let rwindows = non_empty.rwindows(const_size!(3)).unwrap();
+///
+/// assert_eq!(rwindows.len().get(), 2);
+///
+/// let windows: Vec<_> = rwindows.into_iter().collect();
+///
+/// assert_eq!(windows, [non_empty_slice!(&[2, 3, 4]), non_empty_slice!(&[1, 2, 3])]);
+///
+/// assert!(non_empty.rwindows(const_size!(5)).is_none());
+/// ```
+///
+/// [`rwindows`]: NonEmptySlice::rwindows
+#[derive(Debug)]
+pub struct RWindows<'a, T> {
+    slice: &'a NonEmptySlice<T>,
+    size: Size,
+}
+
+impl<'a, T> RWindows<'a, T> {
+    /// Constructs [`Self`] without checking that the size does not exceed the length of the slice.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `size` does not exceed the length of the slice.
+    pub const unsafe fn new_unchecked(slice: &'a NonEmptySlice<T>, size: Size) -> Self {
+        Self { slice, size }
+    }
+
+    /// Returns the number of windows as [`Size`].
+    #[must_use]
+    pub const fn len(&self) -> Size {
+        let len = self.slice.len().get() - self.size.get() + 1;
+
+        // SAFETY: the size does not exceed the length of the slice by construction,
+        // so there is always at least one window
+        unsafe { Size::new_unchecked(len) }
+    }
+
+    /// Checks if there are no windows. Always returns [`false`].
+    ///
+    /// This method is marked as deprecated since there is always at least one window.
+    #[must_use]
+    #[deprecated = "there is always at least one window"]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+impl<'a, T> IntoIterator for RWindows<'a, T> {
+    type Item = &'a NonEmptySlice<T>;
+
+    type IntoIter = Map<Rev<slice::Windows<'a, T>>, NonEmptySliceFn<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice
+            .as_slice()
+            .windows(self.size.get())
+            .rev()
+            // SAFETY: windows are never empty
+            .map(|window| unsafe { NonEmptySlice::from_slice_unchecked(window) })
+    }
+}
+
+unsafe impl<T> NonEmptyIterator for RWindows<'_, T> {}
+
+impl<T> Clone for RWindows<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RWindows<'_, T> {}
+
+impl<'a, T> IntoIterator for &RWindows<'a, T> {
+    type Item = <RWindows<'a, T> as IntoIterator>::Item;
+
+    type IntoIter = <RWindows<'a, T> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
+unsafe impl<T> NonEmptyIterator for &RWindows<'_, T> {}
+
+/// Represents functions mapping windows to arrays.
+///
+/// This is mostly an implementation detail, though it can be useful in case
+/// one needs to name the type of the iterator explicitly.
+pub type ArrayFn<'a, T, const N: usize> = fn(&'a [T]) -> &'a [T; N];
+
+/// Represents non-empty iterators over non-empty slices in (overlapping) windows
+/// of `N` items as [`[T; N]`](prim@array).
+///
+/// This `struct` is created by the [`array_windows`] method on [`NonEmptySlice<T>`].
+///
+/// [`array_windows`]: NonEmptySlice::array_windows
+#[derive(Debug)]
+pub struct ArrayWindows<'a, T, const N: usize> {
+    slice: &'a NonEmptySlice<T>,
+}
+
+impl<'a, T, const N: usize> ArrayWindows<'a, T, N> {
+    /// Constructs [`Self`] without checking that `N` is non-zero
+    /// and does not exceed the length of the slice.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `N` is non-zero and does not exceed the length of the slice.
+    pub const unsafe fn new_unchecked(slice: &'a NonEmptySlice<T>) -> Self {
+        Self { slice }
+    }
+
+    /// Returns the number of windows as [`Size`].
+    #[must_use]
+    pub const fn len(&self) -> Size {
+        let len = self.slice.len().get() - N + 1;
+
+        // SAFETY: `N` does not exceed the length of the slice by construction,
+        // so there is always at least one window
+        unsafe { Size::new_unchecked(len) }
+    }
+
+    /// Checks if there are no windows. Always returns [`false`].
+    ///
+    /// This method is marked as deprecated since there is always at least one window.
+    #[must_use]
+    #[deprecated = "there is always at least one window"]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for ArrayWindows<'a, T, N> {
+    type Item = &'a [T; N];
+
+    type IntoIter = Map<slice::Windows<'a, T>, ArrayFn<'a, T, N>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice
+            .as_slice()
+            .windows(N)
+            // SAFETY: windows are exactly `N` items long
+            .map(|window| unsafe { &*window.as_ptr().cast::<[T; N]>() })
+    }
+}
+
+unsafe impl<T, const N: usize> NonEmptyIterator for ArrayWindows<'_, T, N> {}
+
+impl<T, const N: usize> Clone for ArrayWindows<'_, T, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for ArrayWindows<'_, T, N> {}
+
+impl<'a, T, const N: usize> IntoIterator for &ArrayWindows<'a, T, N> {
+    type Item = <ArrayWindows<'a, T, N> as IntoIterator>::Item;
+
+    type IntoIter = <ArrayWindows<'a, T, N> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
+unsafe impl<T, const N: usize> NonEmptyIterator for &ArrayWindows<'_, T, N> {}
+
+/// Represents iterators over pairs of adjacent items of non-empty slices.
+///
+/// Unlike most other iterators here, this one is *not* non-empty,
+/// since slices of one item have no adjacent pairs.
+///
+/// This `struct` is created by the [`adjacent_pairs`] method on [`NonEmptySlice<T>`].
+///
+/// [`adjacent_pairs`]: NonEmptySlice::adjacent_pairs
+#[derive(Debug)]
+pub struct AdjacentPairs<'a, T> {
+    windows: slice::Windows<'a, T>,
+}
+
+impl<'a, T> AdjacentPairs<'a, T> {
+    /// Constructs [`Self`].
+    #[must_use]
+    pub fn new(slice: &'a NonEmptySlice<T>) -> Self {
+        Self {
+            windows: slice.as_slice().windows(2),
+        }
+    }
+}
+
+impl<T> Clone for AdjacentPairs<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            windows: self.windows.clone(),
+        }
+    }
+}
+
+fn pair<T>(window: &[T]) -> (&T, &T) {
+    (&window[0], &window[1])
+}
+
+impl<'a, T> Iterator for AdjacentPairs<'a, T> {
+    type Item = (&'a T, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.windows.next().map(pair)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windows.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for AdjacentPairs<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.windows.next_back().map(pair)
+    }
+}
+
+impl<T> ExactSizeIterator for AdjacentPairs<'_, T> {}
+
+impl<T> FusedIterator for AdjacentPairs<'_, T> {}
+
 /// Represents non-empty iterators over non-empty slices in (non-overlapping) chunks,
 /// separated by the given predicate.
 ///
 /// This `struct` is created by the [`chunk_by`] method on [`NonEmptySlice<T>`].
 ///
+/// # Examples
+///
+/// Flattening the chunks back into non-empty vector:
+///
+/// ```
+/// use non_empty_slice::non_empty_slice;
+///
+/// let non_empty = non_empty_slice!(&[1, 1, 2, 3, 3]);
+///
+/// let flattened = non_empty
+///     .chunk_by(|left, right| left == right)
+///     .collect_into_non_empty_vec();
+///
+/// assert_eq!(flattened, [1, 1, 2, 3, 3]);
+/// ```
+///
 /// [`chunk_by`]: NonEmptySlice::chunk_by
 pub struct ChunkBy<'a, T, P: FnMut(&T, &T) -> bool> {
     slice: &'a NonEmptySlice<T>,
@@ -409,6 +1072,24 @@ impl<'a, T, P: FnMut(&T, &T) -> bool> IntoIterator for ChunkBy<'a, T, P> {
 
 unsafe impl<T, P: FnMut(&T, &T) -> bool> NonEmptyIterator for ChunkBy<'_, T, P> {}
 
+impl<T, P: FnMut(&T, &T) -> bool + Clone> Clone for ChunkBy<'_, T, P> {
+    fn clone(&self) -> Self {
+        Self::new(self.slice, self.predicate.clone())
+    }
+}
+
+impl<'a, T, P: FnMut(&T, &T) -> bool + Clone> IntoIterator for &ChunkBy<'a, T, P> {
+    type Item = &'a NonEmptySlice<T>;
+
+    type IntoIter = <ChunkBy<'a, T, P> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.clone().into_iter()
+    }
+}
+
+unsafe impl<T, P: FnMut(&T, &T) -> bool + Clone> NonEmptyIterator for &ChunkBy<'_, T, P> {}
+
 /// Represents non-empty iterators over non-empty slices in (non-overlapping) mutable chunks,
 /// separated by the given predicate.
 ///
@@ -452,6 +1133,174 @@ impl<'a, T, P: FnMut(&T, &T) -> bool> IntoIterator for ChunkByMut<'a, T, P> {
 
 unsafe impl<T, P: FnMut(&T, &T) -> bool> NonEmptyIterator for ChunkByMut<'_, T, P> {}
 
+/// Represents non-empty iterators over non-empty slices in (non-overlapping) chunks,
+/// separated by items that match the given predicate, which are included in the chunks.
+///
+/// This `struct` is created by the [`split_inclusive`] method on [`NonEmptySlice<T>`].
+///
+/// [`split_inclusive`]: NonEmptySlice::split_inclusive
+pub struct SplitInclusive<'a, T, P: FnMut(&T) -> bool> {
+    slice: &'a NonEmptySlice<T>,
+    predicate: P,
+}
+
+impl<T: fmt::Debug, P: FnMut(&T) -> bool> fmt::Debug for SplitInclusive<'_, T, P> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct(stringify!(SplitInclusive))
+            .field(stringify!(slice), &self.slice)
+            .finish()
+    }
+}
+
+impl<'a, T, P: FnMut(&T) -> bool> SplitInclusive<'a, T, P> {
+    /// Constructs [`Self`].
+    pub const fn new(slice: &'a NonEmptySlice<T>, predicate: P) -> Self {
+        Self { slice, predicate }
+    }
+}
+
+impl<'a, T, P: FnMut(&T) -> bool> IntoIterator for SplitInclusive<'a, T, P> {
+    type Item = &'a NonEmptySlice<T>;
+
+    type IntoIter = Map<slice::SplitInclusive<'a, T, P>, NonEmptySliceFn<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice
+            .as_slice()
+            .split_inclusive(self.predicate)
+            // SAFETY: chunks are never empty
+            .map(|chunk| unsafe { NonEmptySlice::from_slice_unchecked(chunk) })
+    }
+}
+
+unsafe impl<T, P: FnMut(&T) -> bool> NonEmptyIterator for SplitInclusive<'_, T, P> {}
+
+impl<T, P: FnMut(&T) -> bool + Clone> Clone for SplitInclusive<'_, T, P> {
+    fn clone(&self) -> Self {
+        Self::new(self.slice, self.predicate.clone())
+    }
+}
+
+impl<'a, T, P: FnMut(&T) -> bool + Clone> IntoIterator for &SplitInclusive<'a, T, P> {
+    type Item = &'a NonEmptySlice<T>;
+
+    type IntoIter = <SplitInclusive<'a, T, P> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.clone().into_iter()
+    }
+}
+
+unsafe impl<T, P: FnMut(&T) -> bool + Clone> NonEmptyIterator for &SplitInclusive<'_, T, P> {}
+
+/// Represents non-empty iterators over non-empty slices in (non-overlapping) mutable chunks,
+/// separated by items that match the given predicate, which are included in the chunks.
+///
+/// This `struct` is created by the [`split_inclusive_mut`] method on [`NonEmptySlice<T>`].
+///
+/// [`split_inclusive_mut`]: NonEmptySlice::split_inclusive_mut
+pub struct SplitInclusiveMut<'a, T, P: FnMut(&T) -> bool> {
+    slice: &'a mut NonEmptySlice<T>,
+    predicate: P,
+}
+
+impl<T: fmt::Debug, P: FnMut(&T) -> bool> fmt::Debug for SplitInclusiveMut<'_, T, P> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct(stringify!(SplitInclusiveMut))
+            .field(stringify!(slice), &self.slice)
+            .finish()
+    }
+}
+
+impl<'a, T, P: FnMut(&T) -> bool> SplitInclusiveMut<'a, T, P> {
+    /// Constructs [`Self`].
+    pub const fn new(slice: &'a mut NonEmptySlice<T>, predicate: P) -> Self {
+        Self { slice, predicate }
+    }
+}
+
+impl<'a, T, P: FnMut(&T) -> bool> IntoIterator for SplitInclusiveMut<'a, T, P> {
+    type Item = &'a mut NonEmptySlice<T>;
+
+    type IntoIter = Map<slice::SplitInclusiveMut<'a, T, P>, NonEmptyMutSliceFn<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice
+            .as_mut_slice()
+            .split_inclusive_mut(self.predicate)
+            // SAFETY: chunks are never empty
+            .map(|chunk| unsafe { NonEmptySlice::from_mut_slice_unchecked(chunk) })
+    }
+}
+
+unsafe impl<T, P: FnMut(&T) -> bool> NonEmptyIterator for SplitInclusiveMut<'_, T, P> {}
+
+/// Represents functions mapping runs of equal items to the item and the length of the run.
+///
+/// This is mostly an implementation detail, though it can be useful in case
+/// one needs to name the type of the iterator explicitly.
+pub type RunLengthFn<'a, T> = fn(&'a [T]) -> (&'a T, Size);
+
+/// Represents non-empty iterators over runs of equal items in non-empty slices,
+/// yielding the first item of each run along with the length of the run.
+///
+/// This `struct` is created by the [`run_lengths`] method on [`NonEmptySlice<T>`].
+///
+/// [`run_lengths`]: NonEmptySlice::run_lengths
+#[derive(Debug)]
+pub struct RunLengths<'a, T> {
+    slice: &'a NonEmptySlice<T>,
+}
+
+impl<'a, T> RunLengths<'a, T> {
+    /// Constructs [`Self`].
+    pub const fn new(slice: &'a NonEmptySlice<T>) -> Self {
+        Self { slice }
+    }
+}
+
+impl<'a, T: PartialEq> IntoIterator for RunLengths<'a, T> {
+    type Item = (&'a T, Size);
+
+    type IntoIter = Map<slice::ChunkBy<'a, T, fn(&T, &T) -> bool>, RunLengthFn<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice
+            .as_slice()
+            .chunk_by(T::eq as fn(&T, &T) -> bool)
+            .map(|run| {
+                // SAFETY: runs are never empty
+                let non_empty = unsafe { NonEmptySlice::from_slice_unchecked(run) };
+
+                (non_empty.first(), non_empty.len())
+            })
+    }
+}
+
+unsafe impl<T: PartialEq> NonEmptyIterator for RunLengths<'_, T> {}
+
+impl<T> Clone for RunLengths<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RunLengths<'_, T> {}
+
+impl<'a, T: PartialEq> IntoIterator for &RunLengths<'a, T> {
+    type Item = <RunLengths<'a, T> as IntoIterator>::Item;
+
+    type IntoIter = <RunLengths<'a, T> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
+unsafe impl<T: PartialEq> NonEmptyIterator for &RunLengths<'_, T> {}
+
 /// Represents non-empty iterators that produce escaped versions of provided slices,
 /// treating them as ASCII strings.
 ///
@@ -482,3 +1331,167 @@ impl<'a> IntoIterator for EscapeAscii<'a> {
 }
 
 unsafe impl NonEmptyIterator for EscapeAscii<'_> {}
+
+impl Clone for EscapeAscii<'_> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for EscapeAscii<'_> {}
+
+impl<'a> IntoIterator for &EscapeAscii<'a> {
+    type Item = u8;
+
+    type IntoIter = slice::EscapeAscii<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
+unsafe impl NonEmptyIterator for &EscapeAscii<'_> {}
+
+impl fmt::Display for EscapeAscii<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.bytes.as_slice().escape_ascii().fmt(formatter)
+    }
+}
+
+/// Represents non-empty iterators over subslices of non-empty bytes, separated by the given byte.
+///
+/// This `struct` is created by the [`split_on_byte`] method on [`NonEmptyBytes`].
+///
+/// [`split_on_byte`]: NonEmptyBytes::split_on_byte
+#[derive(Debug)]
+pub struct SplitOnByte<'a> {
+    bytes: &'a NonEmptyBytes,
+    byte: u8,
+}
+
+impl<'a> SplitOnByte<'a> {
+    /// Constructs [`Self`].
+    #[must_use]
+    pub const fn new(bytes: &'a NonEmptyBytes, byte: u8) -> Self {
+        Self { bytes, byte }
+    }
+}
+
+impl<'a> IntoIterator for SplitOnByte<'a> {
+    type Item = &'a [u8];
+
+    type IntoIter = SplitOnByteIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SplitOnByteIter::new(self.bytes.as_slice(), self.byte)
+    }
+}
+
+// NOTE: splitting always yields at least one (possibly empty) subslice
+
+unsafe impl NonEmptyIterator for SplitOnByte<'_> {}
+
+impl Clone for SplitOnByte<'_> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for SplitOnByte<'_> {}
+
+impl<'a> IntoIterator for &SplitOnByte<'a> {
+    type Item = &'a [u8];
+
+    type IntoIter = SplitOnByteIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
+unsafe impl NonEmptyIterator for &SplitOnByte<'_> {}
+
+/// Represents iterators over subslices of bytes, separated by the given byte.
+///
+/// This `struct` is created by calling [`into_iter`] on [`SplitOnByte`].
+///
+/// [`into_iter`]: IntoIterator::into_iter
+#[derive(Debug, Clone)]
+pub struct SplitOnByteIter<'a> {
+    remaining: Option<&'a [u8]>,
+    byte: u8,
+}
+
+impl<'a> SplitOnByteIter<'a> {
+    const fn new(bytes: &'a [u8], byte: u8) -> Self {
+        Self {
+            remaining: Some(bytes),
+            byte,
+        }
+    }
+}
+
+impl<'a> Iterator for SplitOnByteIter<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining?;
+
+        let Some(index) = search::find_byte(remaining, self.byte) else {
+            return self.remaining.take();
+        };
+
+        let (head, tail) = remaining.split_at(index);
+
+        self.remaining = Some(&tail[1..]);
+
+        Some(head)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.remaining
+            .map_or((0, Some(0)), |remaining| (1, Some(remaining.len() + 1)))
+    }
+}
+
+impl DoubleEndedIterator for SplitOnByteIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining?;
+
+        let Some(index) = search::rfind_byte(remaining, self.byte) else {
+            return self.remaining.take();
+        };
+
+        let (head, tail) = remaining.split_at(index);
+
+        self.remaining = Some(head);
+
+        Some(&tail[1..])
+    }
+}
+
+impl FusedIterator for SplitOnByteIter<'_> {}
+
+non_empty_adapters! {
+    {'a, T} Chunks<'a, T>,
+    {'a, T} ChunksMut<'a, T>,
+    {'a, T} RChunks<'a, T>,
+    {'a, T} RChunksMut<'a, T>,
+    {'a, T} RWindows<'a, T>,
+    {'a, T, const N: usize} ArrayWindows<'a, T, N>,
+    {'a, T, P: FnMut(&T, &T) -> bool} ChunkBy<'a, T, P>,
+    {'a, T, P: FnMut(&T, &T) -> bool} ChunkByMut<'a, T, P>,
+    {'a, T, P: FnMut(&T) -> bool} SplitInclusive<'a, T, P>,
+    {'a, T, P: FnMut(&T) -> bool} SplitInclusiveMut<'a, T, P>,
+    {'a, T} RunLengths<'a, T> {T: PartialEq},
+    {'a} EscapeAscii<'a>,
+    {'a} SplitOnByte<'a>,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+collect_into_non_empty_vec! {
+    {'a, T} Chunks<'a, T>,
+    {'a, T} RChunks<'a, T>,
+    {'a, T, P: FnMut(&T, &T) -> bool} ChunkBy<'a, T, P>,
+    {'a, T, P: FnMut(&T) -> bool} SplitInclusive<'a, T, P>,
+}
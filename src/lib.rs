@@ -11,13 +11,30 @@ extern crate alloc;
 #[macro_use]
 pub mod macros;
 
+#[doc(no_inline)]
+pub use non_zero_size::{Size, const_size, size};
+
 pub mod slice;
 
+pub mod str;
+
+#[doc(inline)]
+pub use str::{EmptyStr, NonEmptyStr};
+
 pub mod iter;
 
+pub(crate) mod search;
+
 #[doc(inline)]
 pub use slice::{EmptySlice, NonEmptyBytes, NonEmptySlice};
 
+pub mod hex;
+
+pub mod display;
+
+#[cfg(feature = "base64")]
+pub mod base64;
+
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub mod boxed;
 
@@ -32,6 +49,20 @@ pub mod vec;
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub use vec::{EmptyByteVec, EmptyVec, NonEmptyByteVec, NonEmptyVec};
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod bounded;
+
+#[doc(inline)]
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use bounded::{BoundExceeded, NonEmptyBoundedVec};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod string;
+
+#[doc(inline)]
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use string::{EmptyString, NonEmptyString};
+
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub mod cow;
 
@@ -39,14 +70,73 @@ pub mod cow;
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub use cow::NonEmptyCowSlice;
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod shared;
+
+#[doc(inline)]
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use shared::{EmptyArcSlice, EmptyRcSlice, NonEmptyArcSlice, NonEmptyRcSlice};
+
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub(crate) mod format;
 
 #[cfg(feature = "std")]
-pub(crate) mod io;
+pub mod io;
+
+#[doc(inline)]
+#[cfg(feature = "std")]
+pub use io::NonEmptyCursor;
+
+#[cfg(all(feature = "bytes", any(feature = "std", feature = "alloc")))]
+pub mod buf;
+
+#[doc(inline)]
+#[cfg(all(feature = "bytes", any(feature = "std", feature = "alloc")))]
+pub use buf::{EmptyBytesMut, EmptySharedBytes, NonEmptyBytesMut, NonEmptySharedBytes};
+
+#[cfg(all(feature = "smallvec", any(feature = "std", feature = "alloc")))]
+pub mod small_vec;
+
+#[doc(inline)]
+#[cfg(all(feature = "smallvec", any(feature = "std", feature = "alloc")))]
+pub use small_vec::{EmptySmallVec, NonEmptySmallVec};
+
+#[cfg(all(feature = "rkyv", any(feature = "std", feature = "alloc")))]
+pub mod archived;
+
+#[doc(inline)]
+#[cfg(all(feature = "rkyv", any(feature = "std", feature = "alloc")))]
+pub use archived::ArchivedNonEmptyVec;
+
+#[cfg(feature = "proptest")]
+pub mod strategy;
+
+#[cfg(feature = "arbitrary")]
+pub(crate) mod arbitrary;
 
 #[cfg(feature = "ownership")]
 pub(crate) mod ownership;
 
 #[cfg(feature = "serde")]
-pub(crate) mod serde;
+pub mod serde;
+
+#[cfg(feature = "bincode")]
+pub(crate) mod bincode;
+
+#[cfg(feature = "subtle")]
+pub(crate) mod subtle;
+
+#[cfg(feature = "zeroize")]
+pub(crate) mod zeroize;
+
+#[cfg(feature = "rayon")]
+pub(crate) mod rayon;
+
+#[cfg(feature = "bytemuck")]
+pub(crate) mod bytemuck;
+
+#[cfg(all(feature = "schemars", any(feature = "std", feature = "alloc")))]
+pub(crate) mod schemars;
+
+#[cfg(all(feature = "borsh", any(feature = "std", feature = "alloc")))]
+pub(crate) mod borsh;
@@ -13,6 +13,46 @@ pub mod import {
     pub use non_zero_size::const_size;
 }
 
+#[doc(hidden)]
+pub mod concat {
+    use core::mem::MaybeUninit;
+
+    /// Concatenates the given slices into the array of length `N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total length of the slices is not equal to `N`.
+    pub const fn concat<T: Copy, const N: usize>(slices: &[&[T]]) -> [T; N] {
+        let mut output = [MaybeUninit::uninit(); N];
+
+        let mut index = 0;
+        let mut outer = 0;
+
+        while outer < slices.len() {
+            let slice = slices[outer];
+
+            let mut inner = 0;
+
+            while inner < slice.len() {
+                assert!(index < N, "the total length exceeds the expected one");
+
+                output[index] = MaybeUninit::new(slice[inner]);
+
+                index += 1;
+                inner += 1;
+            }
+
+            outer += 1;
+        }
+
+        assert!(index == N, "the total length is less than the expected one");
+
+        // SAFETY: all `N` items are initialized at this point
+        // moreover, `MaybeUninit<T>` has the same layout as `T`, so it is safe to read
+        unsafe { (&raw const output).cast::<[T; N]>().read() }
+    }
+}
+
 /// Constructs [`NonEmptyVec<T>`] containing the provided arguments.
 ///
 /// # Examples
@@ -85,6 +125,80 @@ macro_rules! non_empty_vec {
     }};
 }
 
+/// Similar to [`non_empty_vec!`], but constructs [`NonEmptyBoxedSlice<T>`].
+///
+/// Accepts the same arguments as [`non_empty_vec!`].
+///
+/// # Examples
+///
+/// Providing no arguments results in compile-time errors:
+///
+/// ```compile_fail
+/// use non_empty_slice::non_empty_boxed;
+///
+/// let never = non_empty_boxed![];
+/// ```
+///
+/// Providing multiple arguments:
+///
+/// ```
+/// use non_empty_slice::{NonEmptyBoxedSlice, non_empty_boxed};
+///
+/// let nice: NonEmptyBoxedSlice<i32> = non_empty_boxed![13, 42, 69];
+///
+/// assert_eq!(nice.as_slice(), [13, 42, 69]);
+/// ```
+///
+/// [`NonEmptyBoxedSlice<T>`]: crate::boxed::NonEmptyBoxedSlice
+#[macro_export]
+#[cfg(any(feature = "std", feature = "alloc"))]
+macro_rules! non_empty_boxed {
+    () => {
+        $crate::macros::import::compile_error!("expected non-empty arguments");
+    };
+    ($($arguments: tt)+) => {
+        $crate::non_empty_vec![$($arguments)+].into_non_empty_boxed_slice()
+    };
+}
+
+/// Similar to [`non_empty_vec!`], but constructs owned [`NonEmptyCowSlice<'_, T>`].
+///
+/// Accepts the same arguments as [`non_empty_vec!`].
+///
+/// # Examples
+///
+/// Providing no arguments results in compile-time errors:
+///
+/// ```compile_fail
+/// use non_empty_slice::non_empty_cow;
+///
+/// let never = non_empty_cow![];
+/// ```
+///
+/// Providing argument and non-zero count:
+///
+/// ```
+/// use non_empty_slice::{cow::{self, NonEmptyCowSlice}, non_empty_cow};
+///
+/// let repeated: NonEmptyCowSlice<'_, i32> = non_empty_cow![13; const 3];
+///
+/// assert!(cow::is_owned(&repeated));
+///
+/// assert_eq!(repeated.as_slice(), [13, 13, 13]);
+/// ```
+///
+/// [`NonEmptyCowSlice<'_, T>`]: crate::cow::NonEmptyCowSlice
+#[macro_export]
+#[cfg(any(feature = "std", feature = "alloc"))]
+macro_rules! non_empty_cow {
+    () => {
+        $crate::macros::import::compile_error!("expected non-empty arguments");
+    };
+    ($($arguments: tt)+) => {
+        $crate::cow::NonEmptyCowSlice::Owned($crate::non_empty_vec![$($arguments)+])
+    };
+}
+
 /// Constructs [`NonEmptySlice`] from the given slice, panicking if it is empty.
 ///
 /// [`NonEmptySlice`]: crate::slice::NonEmptySlice
@@ -141,6 +255,42 @@ macro_rules! const_non_empty_slice {
     };
 }
 
+/// Constructs `&'static` [`NonEmptySlice<T>`] from the given items in `const` contexts.
+///
+/// The length is inferred from the number of items provided, and providing no items
+/// results in compile-time errors. Note that the items must be const-evaluatable.
+///
+/// # Examples
+///
+/// Defining static tables:
+///
+/// ```
+/// use non_empty_slice::{NonEmptySlice, const_non_empty_array};
+///
+/// static TABLE: &NonEmptySlice<u32> = const_non_empty_array![1, 2, 3];
+///
+/// assert_eq!(TABLE.len().get(), 3);
+/// ```
+///
+/// Providing no items results in compile-time errors:
+///
+/// ```compile_fail
+/// use non_empty_slice::const_non_empty_array;
+///
+/// let never = const_non_empty_array![];
+/// ```
+///
+/// [`NonEmptySlice<T>`]: crate::slice::NonEmptySlice
+#[macro_export]
+macro_rules! const_non_empty_array {
+    () => {
+        $crate::macros::import::compile_error!("expected non-empty arguments");
+    };
+    ($($item: expr),+ $(,)?) => {
+        const { $crate::slice::NonEmptySlice::from_array(&[$($item),+]) }
+    };
+}
+
 /// Similar to [`non_empty_bytes!`] but for `const` contexts.
 ///
 /// Note that the provided expression must be const-evaluatable, else the compilation will fail.
@@ -166,3 +316,47 @@ macro_rules! const_non_empty_bytes {
         const { $crate::non_empty_bytes!($bytes) }
     };
 }
+
+/// Concatenates the given slices into [`NonEmptySlice<T>`] at compile time.
+///
+/// All provided expressions must be const-evaluatable slices (or references to arrays)
+/// of items implementing [`Copy`], and at least one of them must be non-empty,
+/// else the compilation will fail.
+///
+/// # Examples
+///
+/// Building protocol preambles from parts:
+///
+/// ```
+/// use non_empty_slice::{NonEmptyBytes, non_empty_concat};
+///
+/// const MAGIC: &[u8] = b"NEKIT";
+/// const VERSION: &[u8] = &[1, 3];
+///
+/// let preamble: &NonEmptyBytes = non_empty_concat!(MAGIC, b"", VERSION, &[0]);
+///
+/// assert_eq!(preamble, b"NEKIT\x01\x03\x00");
+/// ```
+///
+/// Failing compilation when all slices are empty:
+///
+/// ```compile_fail
+/// use non_empty_slice::non_empty_concat;
+///
+/// let never = non_empty_concat!(b"", b"");
+/// ```
+///
+/// [`NonEmptySlice<T>`]: crate::slice::NonEmptySlice
+#[macro_export]
+macro_rules! non_empty_concat {
+    ($($slice: expr),+ $(,)?) => {
+        const {
+            const LENGTH: usize = 0 $(+ $slice.len())+;
+
+            $crate::slice::NonEmptySlice::from_slice(const {
+                &$crate::macros::concat::concat::<_, LENGTH>(&[$($slice),+])
+            })
+            .expect($crate::slice::EMPTY_SLICE)
+        }
+    };
+}
@@ -0,0 +1,22 @@
+#[cfg(not(feature = "rayon"))]
+compile_error!("expected `rayon` to be enabled");
+
+use rayon::slice::{ParallelSlice, ParallelSliceMut};
+
+use crate::slice::NonEmptySlice;
+
+// NOTE: implementing these traits provides `par_sort`, `par_sort_by`, `par_sort_by_key`,
+// `par_sort_unstable` and the rest of parallel slice methods directly on non-empty slices;
+// non-empty vectors get them via dereferencing
+
+impl<T: Sync> ParallelSlice<T> for NonEmptySlice<T> {
+    fn as_parallel_slice(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: Send> ParallelSliceMut<T> for NonEmptySlice<T> {
+    fn as_parallel_slice_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(not(feature = "schemars"))]
+compile_error!("expected `schemars` to be enabled");
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("expected either `std` or `alloc` to be enabled");
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{borrow::Cow, format};
+
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+use crate::{slice::NonEmptySlice, vec::NonEmptyVec};
+
+impl<T: JsonSchema> JsonSchema for NonEmptySlice<T> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("NonEmptyArray_of_{}", T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("NonEmpty[{}]", T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "array",
+            "items": generator.subschema_for::<T>(),
+            "minItems": 1,
+        })
+    }
+}
+
+// NOTE: `JsonSchema` is implemented for `Box<U>`, provided `U: JsonSchema + ?Sized`
+// `NonEmptySlice<T>` is `JsonSchema`, therefore `NonEmptyBoxedSlice<T>` is as well
+
+impl<T: JsonSchema> JsonSchema for NonEmptyVec<T> {
+    fn inline_schema() -> bool {
+        NonEmptySlice::<T>::inline_schema()
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        NonEmptySlice::<T>::schema_name()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        NonEmptySlice::<T>::schema_id()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        NonEmptySlice::<T>::json_schema(generator)
+    }
+}
@@ -0,0 +1,44 @@
+// NOTE: the searching functions are always available, falling back to naive implementations
+// when `memchr` is not enabled, so that the public API does not depend on the feature
+
+/// Returns the index of the first occurrence of the given byte.
+#[cfg(feature = "memchr")]
+pub(crate) fn find_byte(haystack: &[u8], byte: u8) -> Option<usize> {
+    memchr::memchr(byte, haystack)
+}
+
+/// Returns the index of the first occurrence of the given byte.
+#[cfg(not(feature = "memchr"))]
+pub(crate) fn find_byte(haystack: &[u8], byte: u8) -> Option<usize> {
+    haystack.iter().position(|&item| item == byte)
+}
+
+/// Returns the index of the last occurrence of the given byte.
+#[cfg(feature = "memchr")]
+pub(crate) fn rfind_byte(haystack: &[u8], byte: u8) -> Option<usize> {
+    memchr::memrchr(byte, haystack)
+}
+
+/// Returns the index of the last occurrence of the given byte.
+#[cfg(not(feature = "memchr"))]
+pub(crate) fn rfind_byte(haystack: &[u8], byte: u8) -> Option<usize> {
+    haystack.iter().rposition(|&item| item == byte)
+}
+
+/// Returns the index of the first occurrence of any of the given bytes.
+#[cfg(feature = "memchr")]
+pub(crate) fn find_any_of(haystack: &[u8], needles: &[u8]) -> Option<usize> {
+    match *needles {
+        [] => None,
+        [one] => memchr::memchr(one, haystack),
+        [one, two] => memchr::memchr2(one, two, haystack),
+        [one, two, three] => memchr::memchr3(one, two, three, haystack),
+        _ => haystack.iter().position(|item| needles.contains(item)),
+    }
+}
+
+/// Returns the index of the first occurrence of any of the given bytes.
+#[cfg(not(feature = "memchr"))]
+pub(crate) fn find_any_of(haystack: &[u8], needles: &[u8]) -> Option<usize> {
+    haystack.iter().position(|item| needles.contains(item))
+}
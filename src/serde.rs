@@ -1,15 +1,17 @@
+//! Helpers for using non-empty slices with `serde`.
+//!
+//! The functions and modules here are meant to be used with `#[serde(with = "...")]`,
+//! `#[serde(serialize_with = "...")]` and `#[serde(deserialize_with = "...")]` attributes.
+
 #[cfg(not(feature = "serde"))]
 compile_error!("expected `serde` to be enabled");
 
 #[cfg(all(not(feature = "std"), feature = "alloc"))]
 use alloc::vec::Vec;
 
-#[cfg(any(feature = "std", feature = "alloc"))]
-use serde::{Deserialize, Deserializer, de::Error};
-
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
 
-use crate::slice::NonEmptySlice;
+use crate::slice::{NonEmptyBytes, NonEmptySlice};
 
 #[cfg(any(feature = "std", feature = "alloc"))]
 use crate::{boxed::NonEmptyBoxedSlice, vec::NonEmptyVec};
@@ -20,6 +22,17 @@ impl<T: Serialize> Serialize for NonEmptySlice<T> {
     }
 }
 
+// NOTE: `serde` only supports borrowing bytes, so `&NonEmptySlice<T>` can not be deserialized
+// for arbitrary `T`; deserialize `NonEmptyVec<T>` instead
+
+impl<'de: 'a, 'a> Deserialize<'de> for &'a NonEmptyBytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let maybe_empty = <&[u8]>::deserialize(deserializer)?;
+
+        NonEmptyBytes::try_from_slice(maybe_empty).map_err(D::Error::custom)
+    }
+}
+
 #[cfg(any(feature = "std", feature = "alloc"))]
 impl<T: Serialize> Serialize for NonEmptyVec<T> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
@@ -47,3 +60,340 @@ impl<'de, T: Deserialize<'de>> Deserialize<'de> for NonEmptyBoxedSlice<T> {
         Ok(non_empty_vec.into_non_empty_boxed_slice())
     }
 }
+
+/// Serializes anything that can be viewed as slice as sequence.
+///
+/// This is meant to be used with `#[serde(serialize_with = "...")]`.
+///
+/// # Errors
+///
+/// Returns errors produced by the serializer.
+pub fn serialize_as_slice<V: AsRef<[T]> + ?Sized, T: Serialize, S: Serializer>(
+    value: &V,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    value.as_ref().serialize(serializer)
+}
+
+/// Deserializes [`NonEmptyVec<T>`] from sequences, failing on empty ones.
+///
+/// This is meant to be used with `#[serde(deserialize_with = "...")]`.
+///
+/// # Errors
+///
+/// Returns errors produced by the deserializer, as well as custom ones
+/// if the sequence is empty.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_slice::serde::deserialize_non_empty_vec;
+/// use serde::de::{IntoDeserializer, value::Error};
+///
+/// let deserializer = IntoDeserializer::<Error>::into_deserializer(vec![13, 42, 69]);
+///
+/// let non_empty = deserialize_non_empty_vec::<_, i32>(deserializer).unwrap();
+///
+/// assert_eq!(non_empty, [13, 42, 69]);
+///
+/// let empty = IntoDeserializer::<Error>::into_deserializer(Vec::<i32>::new());
+///
+/// assert!(deserialize_non_empty_vec::<_, i32>(empty).is_err());
+/// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn deserialize_non_empty_vec<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
+    deserializer: D,
+) -> Result<NonEmptyVec<T>, D::Error> {
+    NonEmptyVec::deserialize(deserializer)
+}
+
+/// Deserializes [`NonEmptyBoxedSlice<T>`] from sequences, failing on empty ones.
+///
+/// This is meant to be used with `#[serde(deserialize_with = "...")]`.
+///
+/// # Errors
+///
+/// Returns errors produced by the deserializer, as well as custom ones
+/// if the sequence is empty.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn deserialize_non_empty_boxed_slice<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
+    deserializer: D,
+) -> Result<NonEmptyBoxedSlice<T>, D::Error> {
+    NonEmptyBoxedSlice::deserialize(deserializer)
+}
+
+/// Treats empty sequences as absent [`NonEmptyVec<T>`] values.
+///
+/// This module is meant to be used with `#[serde(with = "...")]` on fields of type
+/// [`Option<NonEmptyVec<T>>`], typically alongside `#[serde(default)]`.
+///
+/// [`None`] is serialized as empty sequence, and empty sequences deserialize to [`None`].
+/// Self-describing formats that report `null` to sequence visitors also produce [`None`].
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod empty_as_none {
+    #[cfg(all(not(feature = "std"), feature = "alloc"))]
+    use alloc::vec::Vec;
+
+    use core::{fmt, marker::PhantomData};
+
+    use serde::{
+        Deserialize, Deserializer, Serialize, Serializer,
+        de::{Error, SeqAccess, Visitor, value::SeqAccessDeserializer},
+    };
+
+    use crate::vec::NonEmptyVec;
+
+    /// Serializes [`Option<NonEmptyVec<T>>`], writing [`None`] as empty sequence.
+    ///
+    /// # Errors
+    ///
+    /// Returns errors produced by the serializer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::{non_empty_vec, serde::empty_as_none};
+    /// use postcard::{Deserializer, Serializer, ser_flavors::{AllocVec, Flavor}};
+    ///
+    /// let value = Some(non_empty_vec![13, 42, 69]);
+    ///
+    /// let mut serializer = Serializer { output: AllocVec::new() };
+    ///
+    /// empty_as_none::serialize(&value, &mut serializer).unwrap();
+    ///
+    /// let bytes = serializer.output.finalize().unwrap();
+    ///
+    /// let mut deserializer = Deserializer::from_bytes(&bytes);
+    ///
+    /// let round_trip = empty_as_none::deserialize::<_, i32>(&mut deserializer).unwrap();
+    ///
+    /// assert_eq!(round_trip, value);
+    /// assert!(deserializer.finalize().unwrap().is_empty());
+    ///
+    /// let mut serializer = Serializer { output: AllocVec::new() };
+    ///
+    /// empty_as_none::serialize::<i32, _>(&None, &mut serializer).unwrap();
+    ///
+    /// let bytes = serializer.output.finalize().unwrap();
+    ///
+    /// let mut deserializer = Deserializer::from_bytes(&bytes);
+    ///
+    /// let round_trip = empty_as_none::deserialize::<_, i32>(&mut deserializer).unwrap();
+    ///
+    /// assert!(round_trip.is_none());
+    /// assert!(deserializer.finalize().unwrap().is_empty());
+    /// ```
+    pub fn serialize<T: Serialize, S: Serializer>(
+        value: &Option<NonEmptyVec<T>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value
+            .as_ref()
+            .map_or(&[][..], NonEmptyVec::as_slice)
+            .serialize(serializer)
+    }
+
+    /// Deserializes [`Option<NonEmptyVec<T>>`], treating empty sequences as [`None`].
+    ///
+    /// # Errors
+    ///
+    /// Returns errors produced by the deserializer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::serde::empty_as_none;
+    /// use serde::de::{IntoDeserializer, value::Error};
+    ///
+    /// let deserializer = IntoDeserializer::<Error>::into_deserializer(vec![13, 42, 69]);
+    ///
+    /// let value = empty_as_none::deserialize::<_, i32>(deserializer).unwrap();
+    ///
+    /// assert_eq!(value.unwrap(), [13, 42, 69]);
+    ///
+    /// let empty = IntoDeserializer::<Error>::into_deserializer(Vec::<i32>::new());
+    ///
+    /// assert!(empty_as_none::deserialize::<_, i32>(empty).unwrap().is_none());
+    /// ```
+    pub fn deserialize<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
+        deserializer: D,
+    ) -> Result<Option<NonEmptyVec<T>>, D::Error> {
+        deserializer.deserialize_seq(EmptyAsNoneVisitor::new())
+    }
+
+    // NOTE: deserializing via `deserialize_seq` matches the shape written by `serialize`,
+    // which is required for non-self-describing formats; `null` is handled on top of that
+
+    struct EmptyAsNoneVisitor<T> {
+        phantom: PhantomData<T>,
+    }
+
+    impl<T> EmptyAsNoneVisitor<T> {
+        const fn new() -> Self {
+            Self {
+                phantom: PhantomData,
+            }
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Visitor<'de> for EmptyAsNoneVisitor<T> {
+        type Value = Option<NonEmptyVec<T>>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("sequence")
+        }
+
+        fn visit_none<E: Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+            let maybe_empty = Vec::deserialize(SeqAccessDeserializer::new(seq))?;
+
+            Ok(NonEmptyVec::new(maybe_empty).ok())
+        }
+    }
+}
+
+// NOTE: `NonEmptyByteVec` is already serialized as sequence via the generic implementation,
+// so the compact and human-readable representation is provided as opt-in helpers instead
+
+/// Serializes [`NonEmptyByteVec`] as hexadecimal strings in human-readable formats,
+/// and as bytes otherwise.
+///
+/// This module is meant to be used with `#[serde(with = "...")]`.
+///
+/// Deserialization accepts hexadecimal strings in human-readable formats,
+/// and bytes or sequences of bytes otherwise, failing on empty input.
+///
+/// [`NonEmptyByteVec`]: crate::vec::NonEmptyByteVec
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod bytes {
+    #[cfg(all(not(feature = "std"), feature = "alloc"))]
+    use alloc::vec::Vec;
+
+    use core::fmt;
+
+    use serde::{
+        Deserialize, Deserializer, Serializer,
+        de::{Error, SeqAccess, Visitor, value::SeqAccessDeserializer},
+    };
+
+    use crate::{
+        slice::{EMPTY_SLICE, NonEmptyBytes},
+        vec::NonEmptyByteVec,
+    };
+
+    /// Serializes non-empty bytes, depending on whether the format is human-readable.
+    ///
+    /// # Errors
+    ///
+    /// Returns errors produced by the serializer.
+    pub fn serialize<V: AsRef<NonEmptyBytes> + ?Sized, S: Serializer>(
+        value: &V,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let bytes = value.as_ref();
+
+        if serializer.is_human_readable() {
+            serializer.collect_str(&bytes.hex())
+        } else {
+            serializer.serialize_bytes(bytes.as_slice())
+        }
+    }
+
+    /// Deserializes [`NonEmptyByteVec`], depending on whether the format is human-readable.
+    ///
+    /// # Errors
+    ///
+    /// Returns errors produced by the deserializer, as well as custom ones
+    /// if the input is empty or is not valid hexadecimal string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::serde::bytes;
+    /// use serde::de::{IntoDeserializer, value::Error};
+    ///
+    /// let deserializer = IntoDeserializer::<Error>::into_deserializer("deadbeef");
+    ///
+    /// let non_empty = bytes::deserialize(deserializer).unwrap();
+    ///
+    /// assert_eq!(non_empty, [0xDE, 0xAD, 0xBE, 0xEF]);
+    ///
+    /// let empty = IntoDeserializer::<Error>::into_deserializer("");
+    ///
+    /// assert!(bytes::deserialize(empty).is_err());
+    /// ```
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<NonEmptyByteVec, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(BytesVisitor)
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor)
+        }
+    }
+
+    struct BytesVisitor;
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = NonEmptyByteVec;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("non-empty bytes or hex string")
+        }
+
+        fn visit_str<E: Error>(self, string: &str) -> Result<Self::Value, E> {
+            NonEmptyByteVec::from_hex(string).map_err(E::custom)
+        }
+
+        fn visit_bytes<E: Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+            NonEmptyBytes::from_slice(bytes)
+                .map(NonEmptyBytes::to_non_empty_vec)
+                .ok_or_else(|| E::custom(EMPTY_SLICE))
+        }
+
+        fn visit_byte_buf<E: Error>(self, bytes: Vec<u8>) -> Result<Self::Value, E> {
+            NonEmptyByteVec::new(bytes).map_err(E::custom)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+            let maybe_empty = Vec::deserialize(SeqAccessDeserializer::new(seq))?;
+
+            NonEmptyByteVec::new(maybe_empty).map_err(A::Error::custom)
+        }
+    }
+}
+
+/// Serializes [`NonEmptyBoxedBytes`] as hexadecimal strings in human-readable formats,
+/// and as bytes otherwise.
+///
+/// This module is meant to be used with `#[serde(with = "...")]`,
+/// and behaves exactly like [`bytes`].
+///
+/// [`NonEmptyBoxedBytes`]: crate::boxed::NonEmptyBoxedBytes
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod boxed_bytes {
+    use serde::Deserializer;
+
+    use crate::{boxed::NonEmptyBoxedBytes, vec::NonEmptyByteVec};
+
+    pub use super::bytes::serialize;
+
+    /// Deserializes [`NonEmptyBoxedBytes`], depending on whether the format is human-readable.
+    ///
+    /// # Errors
+    ///
+    /// Returns errors produced by the deserializer, as well as custom ones
+    /// if the input is empty or is not valid hexadecimal string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<NonEmptyBoxedBytes, D::Error> {
+        super::bytes::deserialize(deserializer).map(NonEmptyByteVec::into_non_empty_boxed_slice)
+    }
+}
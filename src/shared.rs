@@ -0,0 +1,214 @@
+//! Non-empty [`Rc<[T]>`](Rc) and [`Arc<[T]>`](Arc).
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("expected either `std` or `alloc` to be enabled");
+
+#[cfg(feature = "std")]
+use std::{rc::Rc, sync::Arc};
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{rc::Rc, sync::Arc};
+
+use thiserror::Error;
+
+use crate::{format, slice::NonEmptySlice, vec::NonEmptyVec};
+
+/// Represents non-empty reference-counted slices, [`Rc<NonEmptySlice<T>>`].
+pub type NonEmptyRcSlice<T> = Rc<NonEmptySlice<T>>;
+
+/// Represents non-empty atomically reference-counted slices, [`Arc<NonEmptySlice<T>>`].
+pub type NonEmptyArcSlice<T> = Arc<NonEmptySlice<T>>;
+
+/// The error message used when the reference-counted slice is empty.
+pub const EMPTY_RC_SLICE: &str = "the reference-counted slice is empty";
+
+/// The error message used when the atomically reference-counted slice is empty.
+pub const EMPTY_ARC_SLICE: &str = "the atomically reference-counted slice is empty";
+
+/// Similar to [`EmptyBoxedSlice<T>`], but contains the empty reference-counted slice provided.
+///
+/// [`EmptyBoxedSlice<T>`]: crate::boxed::EmptyBoxedSlice
+#[derive(Error)]
+#[error("{EMPTY_RC_SLICE}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_slice::shared::rc),
+        help("make sure the reference-counted slice is non-empty")
+    )
+)]
+pub struct EmptyRcSlice<T> {
+    rc: Rc<[T]>,
+}
+
+format::debug!(EmptyRcSlice, rc);
+
+impl<T> EmptyRcSlice<T> {
+    // NOTE: this is private to prevent creating this error with non-empty slices
+    pub(crate) const fn new(rc: Rc<[T]>) -> Self {
+        Self { rc }
+    }
+
+    /// Returns the contained empty reference-counted slice.
+    #[must_use]
+    pub fn get(self) -> Rc<[T]> {
+        self.rc
+    }
+}
+
+/// Similar to [`EmptyBoxedSlice<T>`], but contains the empty atomically reference-counted
+/// slice provided.
+///
+/// [`EmptyBoxedSlice<T>`]: crate::boxed::EmptyBoxedSlice
+#[derive(Error)]
+#[error("{EMPTY_ARC_SLICE}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_slice::shared::arc),
+        help("make sure the atomically reference-counted slice is non-empty")
+    )
+)]
+pub struct EmptyArcSlice<T> {
+    arc: Arc<[T]>,
+}
+
+format::debug!(EmptyArcSlice, arc);
+
+impl<T> EmptyArcSlice<T> {
+    // NOTE: this is private to prevent creating this error with non-empty slices
+    pub(crate) const fn new(arc: Arc<[T]>) -> Self {
+        Self { arc }
+    }
+
+    /// Returns the contained empty atomically reference-counted slice.
+    #[must_use]
+    pub fn get(self) -> Arc<[T]> {
+        self.arc
+    }
+}
+
+// NOTE: `TryFrom<Rc<[T]>>` and `TryFrom<Arc<[T]>>` can not be implemented here, since neither
+// `Rc` nor `Arc` are fundamental; see `from_rc_slice` and `from_arc_slice` instead
+
+// NOTE: conversions from `NonEmptyBoxedSlice<T>` are provided by the standard library
+
+impl<T> From<NonEmptyVec<T>> for NonEmptyRcSlice<T> {
+    fn from(non_empty: NonEmptyVec<T>) -> Self {
+        // SAFETY: the vector is non-empty by construction, so is the reference-counted slice
+        unsafe { NonEmptySlice::from_rc_slice_unchecked(non_empty.into_vec().into()) }
+    }
+}
+
+impl<T: Clone> From<&NonEmptySlice<T>> for NonEmptyRcSlice<T> {
+    fn from(non_empty: &NonEmptySlice<T>) -> Self {
+        // SAFETY: the slice is non-empty by construction, so is the reference-counted slice
+        unsafe { NonEmptySlice::from_rc_slice_unchecked(non_empty.as_slice().into()) }
+    }
+}
+
+impl<T> From<NonEmptyVec<T>> for NonEmptyArcSlice<T> {
+    fn from(non_empty: NonEmptyVec<T>) -> Self {
+        // SAFETY: the vector is non-empty by construction, so is the reference-counted slice
+        unsafe { NonEmptySlice::from_arc_slice_unchecked(non_empty.into_vec().into()) }
+    }
+}
+
+impl<T: Clone> From<&NonEmptySlice<T>> for NonEmptyArcSlice<T> {
+    fn from(non_empty: &NonEmptySlice<T>) -> Self {
+        // SAFETY: the slice is non-empty by construction, so is the reference-counted slice
+        unsafe { NonEmptySlice::from_arc_slice_unchecked(non_empty.as_slice().into()) }
+    }
+}
+
+impl<T> NonEmptySlice<T> {
+    /// Constructs [`Self`] from [`Rc<[T]>`](Rc), provided the slice is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyRcSlice<T>`] if the slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    ///
+    /// use non_empty_slice::NonEmptySlice;
+    ///
+    /// let shared: Rc<[i32]> = Rc::from([1, 2, 3]);
+    ///
+    /// let non_empty = NonEmptySlice::from_rc_slice(shared).unwrap();
+    ///
+    /// assert_eq!(non_empty.len().get(), 3);
+    ///
+    /// let empty: Rc<[i32]> = Rc::from([]);
+    ///
+    /// assert!(NonEmptySlice::from_rc_slice(empty).is_err());
+    /// ```
+    pub fn from_rc_slice(rc: Rc<[T]>) -> Result<Rc<Self>, EmptyRcSlice<T>> {
+        if rc.is_empty() {
+            return Err(EmptyRcSlice::new(rc));
+        }
+
+        // SAFETY: the slice is non-empty at this point
+        Ok(unsafe { Self::from_rc_slice_unchecked(rc) })
+    }
+
+    /// Constructs [`Self`] from [`Rc<[T]>`](Rc), without checking if the slice is empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the slice is non-empty.
+    #[must_use]
+    pub unsafe fn from_rc_slice_unchecked(rc: Rc<[T]>) -> Rc<Self> {
+        // SAFETY: the caller must ensure that the slice is non-empty
+        // moreover, `Self` is `repr(transparent)`, so it is safe to transmute
+        // finally, `Rc` is created from the raw pointer existing within this function only
+        unsafe { Rc::from_raw(Rc::into_raw(rc) as *const Self) }
+    }
+
+    /// Converts [`Self`] into [`Rc<[T]>`](Rc).
+    #[must_use]
+    pub fn into_rc_slice(self: Rc<Self>) -> Rc<[T]> {
+        // SAFETY: `Self` is `repr(transparent)`, so it is safe to transmute
+        // moreover, `Rc` is created from the raw pointer existing within this function only
+        unsafe { Rc::from_raw(Rc::into_raw(self) as *const [T]) }
+    }
+
+    /// Constructs [`Self`] from [`Arc<[T]>`](Arc), provided the slice is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyArcSlice<T>`] if the slice is empty.
+    pub fn from_arc_slice(arc: Arc<[T]>) -> Result<Arc<Self>, EmptyArcSlice<T>> {
+        if arc.is_empty() {
+            return Err(EmptyArcSlice::new(arc));
+        }
+
+        // SAFETY: the slice is non-empty at this point
+        Ok(unsafe { Self::from_arc_slice_unchecked(arc) })
+    }
+
+    /// Constructs [`Self`] from [`Arc<[T]>`](Arc), without checking if the slice is empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the slice is non-empty.
+    #[must_use]
+    pub unsafe fn from_arc_slice_unchecked(arc: Arc<[T]>) -> Arc<Self> {
+        // SAFETY: the caller must ensure that the slice is non-empty
+        // moreover, `Self` is `repr(transparent)`, so it is safe to transmute
+        // finally, `Arc` is created from the raw pointer existing within this function only
+        unsafe { Arc::from_raw(Arc::into_raw(arc) as *const Self) }
+    }
+
+    /// Converts [`Self`] into [`Arc<[T]>`](Arc).
+    #[must_use]
+    pub fn into_arc_slice(self: Arc<Self>) -> Arc<[T]> {
+        // SAFETY: `Self` is `repr(transparent)`, so it is safe to transmute
+        // moreover, `Arc` is created from the raw pointer existing within this function only
+        unsafe { Arc::from_raw(Arc::into_raw(self) as *const [T]) }
+    }
+}
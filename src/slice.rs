@@ -2,10 +2,11 @@
 
 use core::{
     array::TryFromSliceError,
-    mem::MaybeUninit,
-    ops::{Deref, DerefMut, Index, IndexMut, Range},
-    ptr,
-    slice::{Iter, IterMut, SliceIndex},
+    cmp::Ordering,
+    mem::{self, MaybeUninit},
+    ops::{Add, Deref, DerefMut, Index, IndexMut, Mul, Range, RangeBounds},
+    ptr::{self, NonNull},
+    slice::{self, Iter, IterMut, SliceIndex},
 };
 
 use non_empty_iter::{IntoNonEmptyIterator, NonEmptyAdapter};
@@ -13,10 +14,14 @@ use non_zero_size::Size;
 use thiserror::Error;
 
 use crate::iter::{
-    ChunkBy, ChunkByMut, Chunks, ChunksExact, ChunksExactMut, ChunksMut, EscapeAscii, NonEmptyIter,
-    NonEmptyIterMut, RChunks, RChunksExact, RChunksExactMut, RChunksMut, Windows,
+    AdjacentPairs, ArrayWindows, ChunkBy, ChunkByMut, Chunks, ChunksExact, ChunksExactMut,
+    ChunksMut, EscapeAscii, NonEmptyEnumerate, NonEmptyEnumerateMut, NonEmptyIter, NonEmptyIterMut,
+    RChunks, RChunksExact, RChunksExactMut, RChunksMut, RWindows, RunLengths, SplitInclusive,
+    SplitInclusiveMut, SplitOnByte, Windows,
 };
 
+use crate::search;
+
 /// The error message used when the slice is empty.
 pub const EMPTY_SLICE: &str = "the slice is empty";
 
@@ -123,6 +128,56 @@ impl<T, I: SliceIndex<[T]>> IndexMut<I> for NonEmptySlice<T> {
     }
 }
 
+impl<T: PartialEq<U>, U> PartialEq<[U]> for NonEmptySlice<T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<NonEmptySlice<U>> for [T] {
+    fn eq(&self, other: &NonEmptySlice<U>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for NonEmptySlice<T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<NonEmptySlice<U>> for [T; N] {
+    fn eq(&self, other: &NonEmptySlice<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialOrd> PartialOrd<[T]> for NonEmptySlice<T> {
+    fn partial_cmp(&self, other: &[T]) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other)
+    }
+}
+
+impl<T: PartialOrd> PartialOrd<NonEmptySlice<T>> for [T] {
+    fn partial_cmp(&self, other: &NonEmptySlice<T>) -> Option<Ordering> {
+        self.partial_cmp(other.as_slice())
+    }
+}
+
+// NOTE: empty arrays are rejected at compile time, see `from_array`
+
+impl<'a, T, const N: usize> From<&'a [T; N]> for &'a NonEmptySlice<T> {
+    fn from(array: &'a [T; N]) -> Self {
+        NonEmptySlice::from_array(array)
+    }
+}
+
+impl<'a, T, const N: usize> From<&'a mut [T; N]> for &'a mut NonEmptySlice<T> {
+    fn from(array: &'a mut [T; N]) -> Self {
+        NonEmptySlice::from_mut_array(array)
+    }
+}
+
 impl<'a, T, const N: usize> TryFrom<&'a NonEmptySlice<T>> for &'a [T; N] {
     type Error = TryFromSliceError;
 
@@ -376,6 +431,100 @@ impl<T> NonEmptySlice<T> {
         unsafe { &mut *(ptr::from_mut(slice) as *mut Self) }
     }
 
+    /// Constructs [`Self`] from immutable [`[T; N]`](prim@array), checking that `N` is non-zero
+    /// at compile time.
+    ///
+    /// # Examples
+    ///
+    /// Basic snippet:
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptySlice;
+    ///
+    /// let non_empty = NonEmptySlice::from_array(&[1, 2, 3]);
+    ///
+    /// assert_eq!(non_empty.len().get(), 3);
+    /// ```
+    ///
+    /// Empty arrays result in compile-time errors:
+    ///
+    /// ```compile_fail
+    /// use non_empty_slice::NonEmptySlice;
+    ///
+    /// let never = NonEmptySlice::<i32>::from_array(&[]);
+    /// ```
+    #[must_use]
+    pub const fn from_array<const N: usize>(array: &[T; N]) -> &Self {
+        const { assert!(N > 0, "{}", EMPTY_SLICE) };
+
+        // SAFETY: `N` is non-zero, so the slice is non-empty
+        unsafe { Self::from_slice_unchecked(array) }
+    }
+
+    /// Constructs [`Self`] from mutable [`[T; N]`](prim@array), checking that `N` is non-zero
+    /// at compile time.
+    #[must_use]
+    pub const fn from_mut_array<const N: usize>(array: &mut [T; N]) -> &mut Self {
+        const { assert!(N > 0, "{}", EMPTY_SLICE) };
+
+        // SAFETY: `N` is non-zero, so the slice is non-empty
+        unsafe { Self::from_mut_slice_unchecked(array) }
+    }
+
+    /// Forms [`Self`] from the pointer and the non-zero length.
+    ///
+    /// This is the non-empty counterpart of [`from_raw_parts`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must uphold all safety requirements of [`from_raw_parts`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ptr::NonNull;
+    ///
+    /// use non_empty_slice::NonEmptySlice;
+    /// use non_zero_size::const_size;
+    ///
+    /// let array = [1, 2, 3];
+    ///
+    /// let ptr = NonNull::from(&array).cast::<i32>();
+    ///
+    /// // SAFETY: the pointer is valid for reads of 3 items
+    /// let non_empty = unsafe { NonEmptySlice::from_raw_parts(ptr, const_size!(3)) };
+    ///
+    /// assert_eq!(non_empty, &array);
+    /// ```
+    ///
+    /// [`from_raw_parts`]: core::slice::from_raw_parts
+    #[must_use]
+    pub const unsafe fn from_raw_parts<'a>(ptr: NonNull<T>, len: Size) -> &'a Self {
+        // SAFETY: the caller must uphold all safety requirements of `from_raw_parts`
+        let slice = unsafe { slice::from_raw_parts(ptr.as_ptr(), len.get()) };
+
+        // SAFETY: the length is non-zero, so is the slice
+        unsafe { Self::from_slice_unchecked(slice) }
+    }
+
+    /// Forms mutable [`Self`] from the pointer and the non-zero length.
+    ///
+    /// This is the non-empty counterpart of [`from_raw_parts_mut`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must uphold all safety requirements of [`from_raw_parts_mut`].
+    ///
+    /// [`from_raw_parts_mut`]: core::slice::from_raw_parts_mut
+    #[must_use]
+    pub const unsafe fn from_raw_parts_mut<'a>(ptr: NonNull<T>, len: Size) -> &'a mut Self {
+        // SAFETY: the caller must uphold all safety requirements of `from_raw_parts_mut`
+        let slice = unsafe { slice::from_raw_parts_mut(ptr.as_ptr(), len.get()) };
+
+        // SAFETY: the length is non-zero, so is the slice
+        unsafe { Self::from_mut_slice_unchecked(slice) }
+    }
+
     #[cfg(feature = "unsafe-assert")]
     const fn assert_non_empty(&self) {
         use core::hint::assert_unchecked;
@@ -463,6 +612,47 @@ impl<T> NonEmptySlice<T> {
         unsafe { NonEmptyAdapter::new(self.iter_mut()) }
     }
 
+    /// Returns non-empty iterator over the slice, yielding items along with their indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&["nekit", "kit"]);
+    ///
+    /// let pairs: Vec<_> = non_empty.enumerated().into_iter().collect();
+    ///
+    /// assert_eq!(pairs, [(0, &"nekit"), (1, &"kit")]);
+    /// ```
+    pub fn enumerated(&self) -> NonEmptyEnumerate<'_, T> {
+        // SAFETY: the slice is non-empty by construction, so is the enumerated iterator
+        unsafe { NonEmptyAdapter::new(self.iter().enumerate()) }
+    }
+
+    /// Returns non-empty iterator over the mutable slice,
+    /// yielding mutable items along with their indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptySlice;
+    ///
+    /// let mut array = [1, 2, 3];
+    ///
+    /// let non_empty = NonEmptySlice::from_mut_slice(&mut array).unwrap();
+    ///
+    /// for (index, item) in non_empty.enumerated_mut() {
+    ///     *item *= index;
+    /// }
+    ///
+    /// assert_eq!(array, [0, 2, 6]);
+    /// ```
+    pub fn enumerated_mut(&mut self) -> NonEmptyEnumerateMut<'_, T> {
+        // SAFETY: the slice is non-empty by construction, so is the enumerated iterator
+        unsafe { NonEmptyAdapter::new(self.iter_mut().enumerate()) }
+    }
+
     /// Returns the first item of the slice.
     ///
     /// Since the slice is guaranteed to be non-empty, this method always returns some value.
@@ -503,6 +693,46 @@ impl<T> NonEmptySlice<T> {
         unsafe { option.unwrap_unchecked() }
     }
 
+    /// Replaces the item at the given index with the given value, returning the old item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let mut vec = non_empty_vec![1, 2, 3];
+    ///
+    /// assert_eq!(vec.replace(1, 13), 2);
+    ///
+    /// assert_eq!(vec, [1, 13, 3]);
+    /// ```
+    pub const fn replace(&mut self, index: usize, value: T) -> T {
+        mem::replace(&mut self.as_mut_slice()[index], value)
+    }
+
+    /// Replaces the last item of the slice with the given value, returning the old item.
+    ///
+    /// Unlike popping and pushing, this method never fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let mut vec = non_empty_vec![1, 2, 3];
+    ///
+    /// assert_eq!(vec.replace_last(13), 3);
+    ///
+    /// assert_eq!(vec, [1, 2, 13]);
+    /// ```
+    pub const fn replace_last(&mut self, value: T) -> T {
+        mem::replace(self.last_mut(), value)
+    }
+
     /// Returns the first and all the rest of the items in the slice.
     pub const fn split_first(&self) -> (&T, &[T]) {
         let option = self.as_slice().split_first();
@@ -527,6 +757,48 @@ impl<T> NonEmptySlice<T> {
         unsafe { option.unwrap_unchecked() }
     }
 
+    /// Returns the first two items of the slice, or [`None`] if there is only one item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3]);
+    ///
+    /// assert_eq!(non_empty.first_two(), Some((&1, &2)));
+    ///
+    /// let single = non_empty_slice!(&[1]);
+    ///
+    /// assert_eq!(single.first_two(), None);
+    /// ```
+    #[must_use]
+    pub const fn first_two(&self) -> Option<(&T, &T)> {
+        match self.as_slice() {
+            [first, second, ..] => Some((first, second)),
+            _ => None,
+        }
+    }
+
+    /// Returns the last two items of the slice, or [`None`] if there is only one item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3]);
+    ///
+    /// assert_eq!(non_empty.last_two(), Some((&2, &3)));
+    /// ```
+    #[must_use]
+    pub const fn last_two(&self) -> Option<(&T, &T)> {
+        match self.as_slice() {
+            [.., second_last, last] => Some((second_last, last)),
+            _ => None,
+        }
+    }
+
     /// Returns the last mutable item and all the rest of the items in the mutable slice.
     pub const fn split_last_mut(&mut self) -> (&mut T, &mut [T]) {
         let option = self.as_mut_slice().split_last_mut();
@@ -599,86 +871,530 @@ impl<T> NonEmptySlice<T> {
         self.as_mut_slice().split_last_chunk_mut()
     }
 
-    /// Returns the raw pointer to the slice.
-    pub const fn as_ptr(&self) -> *const T {
-        self.as_slice().as_ptr()
-    }
+    /// Similar to [`split_first_chunk`], but the rest of the items are required to be non-empty.
+    ///
+    /// If there are less than `N + 1` items, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    ///
+    /// let frame = NonEmptyBytes::from_slice(b"\x00\x01nekit").unwrap();
+    ///
+    /// let (header, body) = frame.split_first_chunk_non_empty::<2>().unwrap();
+    ///
+    /// assert_eq!(header, &[0, 1]);
+    /// assert_eq!(body.as_slice(), b"nekit");
+    ///
+    /// assert!(frame.split_first_chunk_non_empty::<7>().is_none());
+    /// ```
+    ///
+    /// [`split_first_chunk`]: Self::split_first_chunk
+    pub const fn split_first_chunk_non_empty<const N: usize>(&self) -> Option<(&[T; N], &Self)> {
+        let Some((chunk, rest)) = self.split_first_chunk() else {
+            return None;
+        };
 
-    /// Returns the raw mutable pointer to the mutable slice.
-    pub const fn as_mut_ptr(&mut self) -> *mut T {
-        self.as_mut_slice().as_mut_ptr()
-    }
+        let Some(non_empty) = Self::from_slice(rest) else {
+            return None;
+        };
 
-    /// Returns the two raw pointers spanning the slice.
-    ///
-    /// The end pointer is one element past the end of the slice.
-    pub const fn as_ptr_range(&self) -> Range<*const T> {
-        self.as_slice().as_ptr_range()
+        Some((chunk, non_empty))
     }
 
-    /// Returns the two raw mutable pointers spanning the mutable slice.
+    /// Similar to [`split_first_chunk_mut`], but the rest of the items are required
+    /// to be non-empty.
     ///
-    /// The end pointer is one element past the end of the slice.
-    pub const fn as_mut_ptr_range(&mut self) -> Range<*mut T> {
-        self.as_mut_slice().as_mut_ptr_range()
+    /// If there are less than `N + 1` items, [`None`] is returned.
+    ///
+    /// [`split_first_chunk_mut`]: Self::split_first_chunk_mut
+    pub const fn split_first_chunk_non_empty_mut<const N: usize>(
+        &mut self,
+    ) -> Option<(&mut [T; N], &mut Self)> {
+        let Some((chunk, rest)) = self.split_first_chunk_mut() else {
+            return None;
+        };
+
+        let Some(non_empty) = Self::from_mut_slice(rest) else {
+            return None;
+        };
+
+        Some((chunk, non_empty))
     }
 
-    /// Reinterprets the slice as [`[T; N]`](prim@array).
+    /// Similar to [`split_last_chunk`], but the rest of the items are required to be non-empty.
     ///
-    /// If the length is not equal to `N`, [`None`] is returned.
-    pub const fn as_array<const N: usize>(&self) -> Option<&[T; N]> {
-        if self.len().get() == N {
-            let ptr = self.as_ptr().cast();
+    /// If there are less than `N + 1` items, [`None`] is returned.
+    ///
+    /// [`split_last_chunk`]: Self::split_last_chunk
+    pub const fn split_last_chunk_non_empty<const N: usize>(&self) -> Option<(&Self, &[T; N])> {
+        let Some((rest, chunk)) = self.split_last_chunk() else {
+            return None;
+        };
 
-            // SAFETY: length is equal to `N`, so we can reinterpret this
-            let this = unsafe { &*ptr };
+        let Some(non_empty) = Self::from_slice(rest) else {
+            return None;
+        };
 
-            Some(this)
-        } else {
-            None
-        }
+        Some((non_empty, chunk))
     }
 
-    /// Reinterprets the mutable slice as [`[T; N]`](prim@array).
+    /// Similar to [`split_last_chunk_mut`], but the rest of the items are required
+    /// to be non-empty.
     ///
-    /// If the length is not equal to `N`, [`None`] is returned.
-    pub const fn as_mut_array<const N: usize>(&mut self) -> Option<&mut [T; N]> {
-        if self.len().get() == N {
-            let ptr = self.as_mut_ptr().cast();
+    /// If there are less than `N + 1` items, [`None`] is returned.
+    ///
+    /// [`split_last_chunk_mut`]: Self::split_last_chunk_mut
+    pub const fn split_last_chunk_non_empty_mut<const N: usize>(
+        &mut self,
+    ) -> Option<(&mut Self, &mut [T; N])> {
+        let Some((rest, chunk)) = self.split_last_chunk_mut() else {
+            return None;
+        };
 
-            // SAFETY: length is equal to `N`, so we can reinterpret this
-            let this = unsafe { &mut *ptr };
+        let Some(non_empty) = Self::from_mut_slice(rest) else {
+            return None;
+        };
 
-            Some(this)
-        } else {
-            None
-        }
+        Some((non_empty, chunk))
     }
 
-    /// Swaps two items in the slice.
+    /// Returns the first `size` items of the slice as [`Self`].
     ///
-    /// # Panics
+    /// If there are less than `size` items, [`None`] is returned.
     ///
-    /// Panics if `first` or `other` are out of bounds.
-    pub const fn swap(&mut self, first: usize, other: usize) {
-        self.as_mut_slice().swap(first, other);
-    }
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    /// use non_zero_size::const_size;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3]);
+    ///
+    /// let first = non_empty.first_chunk_non_empty(const_size!(2)).unwrap();
+    ///
+    /// assert_eq!(first, &[1, 2]);
+    ///
+    /// assert!(non_empty.first_chunk_non_empty(const_size!(4)).is_none());
+    /// ```
+    pub const fn first_chunk_non_empty(&self, size: Size) -> Option<&Self> {
+        let Some((chunk, _)) = self.as_slice().split_at_checked(size.get()) else {
+            return None;
+        };
 
-    /// Reverses the slice in place.
-    pub const fn reverse(&mut self) {
-        self.as_mut_slice().reverse();
+        // SAFETY: the chunk contains `size` items, which is non-zero
+        Some(unsafe { Self::from_slice_unchecked(chunk) })
     }
 
-    /// Returns non-empty iterator over the slice in (non-overlapping) non-empty chunks
-    /// of given [`Size`], starting at the beginning of the slice.
-    pub const fn chunks(&self, size: Size) -> Chunks<'_, T> {
-        Chunks::new(self, size)
-    }
+    /// Returns the first mutable `size` items of the mutable slice as [`Self`].
+    ///
+    /// If there are less than `size` items, [`None`] is returned.
+    pub const fn first_chunk_non_empty_mut(&mut self, size: Size) -> Option<&mut Self> {
+        let Some((chunk, _)) = self.as_mut_slice().split_at_mut_checked(size.get()) else {
+            return None;
+        };
 
-    /// Returns non-empty iterator over the slice in (non-overlapping) non-empty mutable chunks
-    /// of given [`Size`], starting at the beginning of the slice.
-    pub const fn chunks_mut(&mut self, size: Size) -> ChunksMut<'_, T> {
-        ChunksMut::new(self, size)
+        // SAFETY: the chunk contains `size` items, which is non-zero
+        Some(unsafe { Self::from_mut_slice_unchecked(chunk) })
+    }
+
+    /// Returns the last `size` items of the slice as [`Self`].
+    ///
+    /// If there are less than `size` items, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    /// use non_zero_size::const_size;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3]);
+    ///
+    /// let last = non_empty.last_chunk_non_empty(const_size!(2)).unwrap();
+    ///
+    /// assert_eq!(last, &[2, 3]);
+    ///
+    /// assert!(non_empty.last_chunk_non_empty(const_size!(4)).is_none());
+    /// ```
+    pub const fn last_chunk_non_empty(&self, size: Size) -> Option<&Self> {
+        let Some(index) = self.len().get().checked_sub(size.get()) else {
+            return None;
+        };
+
+        let (_, chunk) = self.as_slice().split_at(index);
+
+        // SAFETY: the chunk contains `size` items, which is non-zero
+        Some(unsafe { Self::from_slice_unchecked(chunk) })
+    }
+
+    /// Returns the last mutable `size` items of the mutable slice as [`Self`].
+    ///
+    /// If there are less than `size` items, [`None`] is returned.
+    pub const fn last_chunk_non_empty_mut(&mut self, size: Size) -> Option<&mut Self> {
+        let Some(index) = self.len().get().checked_sub(size.get()) else {
+            return None;
+        };
+
+        let (_, chunk) = self.as_mut_slice().split_at_mut(index);
+
+        // SAFETY: the chunk contains `size` items, which is non-zero
+        Some(unsafe { Self::from_mut_slice_unchecked(chunk) })
+    }
+
+    /// Returns the raw pointer to the slice.
+    pub const fn as_ptr(&self) -> *const T {
+        self.as_slice().as_ptr()
+    }
+
+    /// Returns the raw mutable pointer to the mutable slice.
+    pub const fn as_mut_ptr(&mut self) -> *mut T {
+        self.as_mut_slice().as_mut_ptr()
+    }
+
+    /// Returns the two raw pointers spanning the slice.
+    ///
+    /// The end pointer is one element past the end of the slice.
+    pub const fn as_ptr_range(&self) -> Range<*const T> {
+        self.as_slice().as_ptr_range()
+    }
+
+    /// Returns the two raw mutable pointers spanning the mutable slice.
+    ///
+    /// The end pointer is one element past the end of the slice.
+    pub const fn as_mut_ptr_range(&mut self) -> Range<*mut T> {
+        self.as_mut_slice().as_mut_ptr_range()
+    }
+
+    /// Returns the non-null pointer to the slice.
+    ///
+    /// Since the slice is non-empty, the pointer is never dangling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[13, 42]);
+    ///
+    /// let ptr = non_empty.as_non_null();
+    ///
+    /// // SAFETY: the pointer is valid for reads, as the slice is non-empty
+    /// assert_eq!(unsafe { ptr.read() }, 13);
+    /// ```
+    pub const fn as_non_null(&self) -> NonNull<T> {
+        // SAFETY: pointers to slices are never null
+        unsafe { NonNull::new_unchecked(self.as_ptr().cast_mut()) }
+    }
+
+    /// Returns the non-null mutable pointer to the mutable slice.
+    ///
+    /// Since the slice is non-empty, the pointer is never dangling.
+    pub const fn as_mut_non_null(&mut self) -> NonNull<T> {
+        // SAFETY: pointers to slices are never null
+        unsafe { NonNull::new_unchecked(self.as_mut_ptr()) }
+    }
+
+    /// Returns the two non-null pointers spanning the slice.
+    ///
+    /// The end pointer is one element past the end of the slice.
+    pub const fn as_non_null_range(&self) -> Range<NonNull<T>> {
+        let start = self.as_non_null();
+
+        // SAFETY: the end pointer is one element past the end of the same allocation
+        let end = unsafe { start.add(self.len().get()) };
+
+        start..end
+    }
+
+    /// Returns the two non-null mutable pointers spanning the mutable slice.
+    ///
+    /// The end pointer is one element past the end of the slice.
+    pub const fn as_mut_non_null_range(&mut self) -> Range<NonNull<T>> {
+        let start = self.as_mut_non_null();
+
+        // SAFETY: the end pointer is one element past the end of the same allocation
+        let end = unsafe { start.add(self.len().get()) };
+
+        start..end
+    }
+
+    /// Reinterprets the slice as [`[T; N]`](prim@array).
+    ///
+    /// If the length is not equal to `N`, [`None`] is returned.
+    pub const fn as_array<const N: usize>(&self) -> Option<&[T; N]> {
+        if self.len().get() == N {
+            let ptr = self.as_ptr().cast();
+
+            // SAFETY: length is equal to `N`, so we can reinterpret this
+            let this = unsafe { &*ptr };
+
+            Some(this)
+        } else {
+            None
+        }
+    }
+
+    /// Reinterprets the mutable slice as [`[T; N]`](prim@array).
+    ///
+    /// If the length is not equal to `N`, [`None`] is returned.
+    pub const fn as_mut_array<const N: usize>(&mut self) -> Option<&mut [T; N]> {
+        if self.len().get() == N {
+            let ptr = self.as_mut_ptr().cast();
+
+            // SAFETY: length is equal to `N`, so we can reinterpret this
+            let this = unsafe { &mut *ptr };
+
+            Some(this)
+        } else {
+            None
+        }
+    }
+
+    /// Folds the slice into the single value, initializing the accumulator
+    /// from the first item and then applying the given function to the rest of the items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&["nekit", "dev"]);
+    ///
+    /// let joined = non_empty.fold_first(
+    ///     |first| first.to_string(),
+    ///     |mut output, item| {
+    ///         output.push('-');
+    ///         output.push_str(item);
+    ///         output
+    ///     },
+    /// );
+    ///
+    /// assert_eq!(joined, "nekit-dev");
+    /// ```
+    pub fn fold_first<U, I: FnOnce(&T) -> U, F: FnMut(U, &T) -> U>(
+        &self,
+        initial: I,
+        function: F,
+    ) -> U {
+        let (first, rest) = self.split_first();
+
+        rest.iter().fold(initial(first), function)
+    }
+
+    /// Swaps two items in the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `first` or `other` are out of bounds.
+    pub const fn swap(&mut self, first: usize, other: usize) {
+        self.as_mut_slice().swap(first, other);
+    }
+
+    /// Returns mutable references to many items at once,
+    /// or [`None`] if any index is out of bounds or if any indices overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptySlice;
+    ///
+    /// let mut array = [1, 2, 3];
+    ///
+    /// let non_empty = NonEmptySlice::from_mut_slice(&mut array).unwrap();
+    ///
+    /// let [first, last] = non_empty.get_disjoint_mut([0, 2]).unwrap();
+    ///
+    /// *first += 10;
+    /// *last += 20;
+    ///
+    /// assert_eq!(array, [11, 2, 23]);
+    ///
+    /// let non_empty = NonEmptySlice::from_mut_slice(&mut array).unwrap();
+    ///
+    /// assert!(non_empty.get_disjoint_mut([1, 1]).is_none());
+    /// ```
+    pub fn get_disjoint_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        self.as_mut_slice().get_disjoint_mut(indices).ok()
+    }
+
+    /// Returns mutable references to many items at once, without doing any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that all indices are in bounds and that no indices overlap.
+    pub unsafe fn get_disjoint_unchecked_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> [&mut T; N] {
+        // SAFETY: the caller must ensure that the indices are in bounds and disjoint
+        unsafe { self.as_mut_slice().get_disjoint_unchecked_mut(indices) }
+    }
+
+    /// Reverses the slice in place.
+    pub const fn reverse(&mut self) {
+        self.as_mut_slice().reverse();
+    }
+
+    /// Fills the slice with items returned by calling the provided function repeatedly.
+    pub fn fill_with<F: FnMut() -> T>(&mut self, function: F) {
+        self.as_mut_slice().fill_with(function);
+    }
+
+    /// Rotates the slice in-place such that the first `mid` items move to the end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the length of the slice.
+    pub fn rotate_left(&mut self, mid: usize) {
+        self.as_mut_slice().rotate_left(mid);
+    }
+
+    /// Rotates the slice in-place such that the last `count` items move to the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is greater than the length of the slice.
+    pub fn rotate_right(&mut self, count: usize) {
+        self.as_mut_slice().rotate_right(count);
+    }
+
+    /// Similar to [`rotate_left`], but returns [`None`] instead of panicking
+    /// if `mid` is greater than the length of the slice.
+    ///
+    /// [`rotate_left`]: Self::rotate_left
+    pub fn rotate_left_checked(&mut self, mid: usize) -> Option<()> {
+        (mid <= self.len().get()).then(|| self.rotate_left(mid))
+    }
+
+    /// Similar to [`rotate_right`], but returns [`None`] instead of panicking
+    /// if `count` is greater than the length of the slice.
+    ///
+    /// [`rotate_right`]: Self::rotate_right
+    pub fn rotate_right_checked(&mut self, count: usize) -> Option<()> {
+        (count <= self.len().get()).then(|| self.rotate_right(count))
+    }
+
+    /// Moves the item at the given index to the front of the slice,
+    /// shifting the items before it one position to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let mut vec = non_empty_vec![1, 2, 3, 4];
+    ///
+    /// vec.move_to_front(2);
+    ///
+    /// assert_eq!(vec, [3, 1, 2, 4]);
+    /// ```
+    pub fn move_to_front(&mut self, index: usize) {
+        self.as_mut_slice()[..=index].rotate_right(1);
+    }
+
+    /// Moves the item at the given index to the back of the slice,
+    /// shifting the items after it one position to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let mut vec = non_empty_vec![1, 2, 3, 4];
+    ///
+    /// vec.move_to_back(1);
+    ///
+    /// assert_eq!(vec, [1, 3, 4, 2]);
+    /// ```
+    pub fn move_to_back(&mut self, index: usize) {
+        self.as_mut_slice()[index..].rotate_left(1);
+    }
+
+    /// Swaps the first and the last items of the slice.
+    ///
+    /// Does nothing if the slice contains only one item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let mut vec = non_empty_vec![1, 2, 3];
+    ///
+    /// vec.swap_first_last();
+    ///
+    /// assert_eq!(vec, [3, 2, 1]);
+    /// ```
+    pub fn swap_first_last(&mut self) {
+        let last = self.len().get() - 1;
+
+        self.as_mut_slice().swap(0, last);
+    }
+
+    /// Reorders the slice such that the item at `index` is at its final sorted position,
+    /// using the given comparison function.
+    ///
+    /// Returns the items before `index`, the item at `index` and the items after `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn select_nth_unstable_by<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        index: usize,
+        compare: F,
+    ) -> (&mut [T], &mut T, &mut [T]) {
+        self.as_mut_slice().select_nth_unstable_by(index, compare)
+    }
+
+    /// Reorders the slice such that the item at `index` is at its final sorted position,
+    /// using the given key extraction function.
+    ///
+    /// Returns the items before `index`, the item at `index` and the items after `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn select_nth_unstable_by_key<K: Ord, F: FnMut(&T) -> K>(
+        &mut self,
+        index: usize,
+        key: F,
+    ) -> (&mut [T], &mut T, &mut [T]) {
+        self.as_mut_slice().select_nth_unstable_by_key(index, key)
+    }
+
+    /// Returns non-empty iterator over the slice in (non-overlapping) non-empty chunks
+    /// of given [`Size`], starting at the beginning of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::{const_size, non_empty_slice};
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3, 4, 5]);
+    ///
+    /// let chunks: Vec<_> = non_empty.chunks(const_size!(2)).into_iter().collect();
+    ///
+    /// assert_eq!(chunks.len(), 3);
+    /// assert_eq!(chunks[2], non_empty_slice!(&[5]));
+    /// ```
+    pub const fn chunks(&self, size: Size) -> Chunks<'_, T> {
+        Chunks::new(self, size)
+    }
+
+    /// Returns non-empty iterator over the slice in (non-overlapping) non-empty mutable chunks
+    /// of given [`Size`], starting at the beginning of the slice.
+    pub const fn chunks_mut(&mut self, size: Size) -> ChunksMut<'_, T> {
+        ChunksMut::new(self, size)
     }
 
     /// Returns non-empty iterator over the slice in (non-overlapping) non-empty chunks
@@ -734,14 +1450,268 @@ impl<T> NonEmptySlice<T> {
         Windows::new(self, size)
     }
 
-    /// Returns non-empty iterator over the slice in (non-overlapping) chunks,
-    /// separated by the given predicate.
-    pub const fn chunk_by<P: FnMut(&T, &T) -> bool>(&self, predicate: P) -> ChunkBy<'_, T, P> {
-        ChunkBy::new(self, predicate)
+    /// Returns non-empty iterator over the slice in (overlapping) windows of given [`Size`],
+    /// starting at the end of the slice.
+    ///
+    /// This is equivalent to reversing [`windows`], without having to collect them first.
+    /// Chunks can be iterated back-to-front in the same manner by calling `rev` on [`chunks`].
+    ///
+    /// Returns [`None`] if the window size is greater than the length of the slice,
+    /// since the iterator would be empty otherwise.
+    ///
+    /// [`windows`]: Self::windows
+    /// [`chunks`]: Self::chunks
+    pub const fn rwindows(&self, size: Size) -> Option<RWindows<'_, T>> {
+        if size.get() > self.len().get() {
+            return None;
+        }
+
+        // SAFETY: the size does not exceed the length of the slice
+        Some(unsafe { RWindows::new_unchecked(self, size) })
     }
 
-    /// Returns non-empty iterator over the slice in (non-overlapping) mutable chunks,
-    /// separated by the given predicate.
+    /// Similar to [`chunks`], except the chunk size is given as [`usize`].
+    ///
+    /// Returns [`None`] if the chunk size is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3, 4, 5]);
+    ///
+    /// let chunks = non_empty.chunks_checked(2).unwrap();
+    ///
+    /// assert_eq!(chunks.len().get(), 3);
+    ///
+    /// assert!(non_empty.chunks_checked(0).is_none());
+    /// ```
+    ///
+    /// [`chunks`]: Self::chunks
+    pub const fn chunks_checked(&self, size: usize) -> Option<Chunks<'_, T>> {
+        match Size::new(size) {
+            Some(size) => Some(self.chunks(size)),
+            None => None,
+        }
+    }
+
+    /// Similar to [`chunks_mut`], except the chunk size is given as [`usize`].
+    ///
+    /// Returns [`None`] if the chunk size is zero.
+    ///
+    /// [`chunks_mut`]: Self::chunks_mut
+    pub const fn chunks_mut_checked(&mut self, size: usize) -> Option<ChunksMut<'_, T>> {
+        match Size::new(size) {
+            Some(size) => Some(self.chunks_mut(size)),
+            None => None,
+        }
+    }
+
+    /// Similar to [`rchunks`], except the chunk size is given as [`usize`].
+    ///
+    /// Returns [`None`] if the chunk size is zero.
+    ///
+    /// [`rchunks`]: Self::rchunks
+    pub const fn rchunks_checked(&self, size: usize) -> Option<RChunks<'_, T>> {
+        match Size::new(size) {
+            Some(size) => Some(self.rchunks(size)),
+            None => None,
+        }
+    }
+
+    /// Similar to [`rchunks_mut`], except the chunk size is given as [`usize`].
+    ///
+    /// Returns [`None`] if the chunk size is zero.
+    ///
+    /// [`rchunks_mut`]: Self::rchunks_mut
+    pub const fn rchunks_mut_checked(&mut self, size: usize) -> Option<RChunksMut<'_, T>> {
+        match Size::new(size) {
+            Some(size) => Some(self.rchunks_mut(size)),
+            None => None,
+        }
+    }
+
+    /// Similar to [`chunks_exact`], except the chunk size is given as [`usize`].
+    ///
+    /// Returns [`None`] if the chunk size is zero or greater than the length of the slice,
+    /// since the iterator would be empty otherwise.
+    ///
+    /// [`chunks_exact`]: Self::chunks_exact
+    pub const fn chunks_exact_checked(&self, size: usize) -> Option<ChunksExact<'_, T>> {
+        if size > self.len().get() {
+            return None;
+        }
+
+        match Size::new(size) {
+            Some(size) => Some(self.chunks_exact(size)),
+            None => None,
+        }
+    }
+
+    /// Similar to [`chunks_exact_mut`], except the chunk size is given as [`usize`].
+    ///
+    /// Returns [`None`] if the chunk size is zero or greater than the length of the slice,
+    /// since the iterator would be empty otherwise.
+    ///
+    /// [`chunks_exact_mut`]: Self::chunks_exact_mut
+    pub const fn chunks_exact_mut_checked(&mut self, size: usize) -> Option<ChunksExactMut<'_, T>> {
+        if size > self.len().get() {
+            return None;
+        }
+
+        match Size::new(size) {
+            Some(size) => Some(self.chunks_exact_mut(size)),
+            None => None,
+        }
+    }
+
+    /// Similar to [`rchunks_exact`], except the chunk size is given as [`usize`].
+    ///
+    /// Returns [`None`] if the chunk size is zero or greater than the length of the slice,
+    /// since the iterator would be empty otherwise.
+    ///
+    /// [`rchunks_exact`]: Self::rchunks_exact
+    pub const fn rchunks_exact_checked(&self, size: usize) -> Option<RChunksExact<'_, T>> {
+        if size > self.len().get() {
+            return None;
+        }
+
+        match Size::new(size) {
+            Some(size) => Some(self.rchunks_exact(size)),
+            None => None,
+        }
+    }
+
+    /// Similar to [`rchunks_exact_mut`], except the chunk size is given as [`usize`].
+    ///
+    /// Returns [`None`] if the chunk size is zero or greater than the length of the slice,
+    /// since the iterator would be empty otherwise.
+    ///
+    /// [`rchunks_exact_mut`]: Self::rchunks_exact_mut
+    pub const fn rchunks_exact_mut_checked(
+        &mut self,
+        size: usize,
+    ) -> Option<RChunksExactMut<'_, T>> {
+        if size > self.len().get() {
+            return None;
+        }
+
+        match Size::new(size) {
+            Some(size) => Some(self.rchunks_exact_mut(size)),
+            None => None,
+        }
+    }
+
+    /// Similar to [`windows`], except the window size is given as [`usize`].
+    ///
+    /// Returns [`None`] if the window size is zero or greater than the length of the slice,
+    /// since the iterator would be empty otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3]);
+    ///
+    /// let windows: Vec<_> = non_empty.windows_checked(2).unwrap().into_iter().collect();
+    ///
+    /// assert_eq!(windows, [non_empty_slice!(&[1, 2]), non_empty_slice!(&[2, 3])]);
+    ///
+    /// assert!(non_empty.windows_checked(0).is_none());
+    /// assert!(non_empty.windows_checked(4).is_none());
+    /// ```
+    ///
+    /// [`windows`]: Self::windows
+    pub const fn windows_checked(&self, size: usize) -> Option<Windows<'_, T>> {
+        if size > self.len().get() {
+            return None;
+        }
+
+        match Size::new(size) {
+            Some(size) => Some(self.windows(size)),
+            None => None,
+        }
+    }
+
+    /// Similar to [`rwindows`], except the window size is given as [`usize`].
+    ///
+    /// Returns [`None`] if the window size is zero or greater than the length of the slice,
+    /// since the iterator would be empty otherwise.
+    ///
+    /// [`rwindows`]: Self::rwindows
+    pub const fn rwindows_checked(&self, size: usize) -> Option<RWindows<'_, T>> {
+        match Size::new(size) {
+            Some(size) => self.rwindows(size),
+            None => None,
+        }
+    }
+
+    /// Returns non-empty iterator over the slice in (overlapping) windows
+    /// of `N` items as [`[T; N]`](prim@array).
+    ///
+    /// If `N` is zero or greater than the length of the slice, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3, 4]);
+    ///
+    /// let sums: Vec<_> = non_empty
+    ///     .array_windows::<2>()
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .map(|[left, right]| left + right)
+    ///     .collect();
+    ///
+    /// assert_eq!(sums, [3, 5, 7]);
+    ///
+    /// assert_eq!(non_empty.array_windows::<2>().unwrap().len().get(), 3);
+    ///
+    /// assert!(non_empty.array_windows::<5>().is_none());
+    /// ```
+    pub const fn array_windows<const N: usize>(&self) -> Option<ArrayWindows<'_, T, N>> {
+        if N == 0 || N > self.len().get() {
+            return None;
+        }
+
+        // SAFETY: `N` is non-zero and does not exceed the length of the slice
+        Some(unsafe { ArrayWindows::new_unchecked(self) })
+    }
+
+    /// Returns iterator over pairs of adjacent items of the slice.
+    ///
+    /// This is similar to [`windows`] of size two, except the pairs are yielded as tuples.
+    /// Note that the iterator is empty if there is only one item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let series = non_empty_slice!(&[1, 4, 9, 16]);
+    ///
+    /// let deltas: Vec<_> = series.adjacent_pairs().map(|(last, next)| next - last).collect();
+    ///
+    /// assert_eq!(deltas, [3, 5, 7]);
+    /// ```
+    ///
+    /// [`windows`]: Self::windows
+    pub fn adjacent_pairs(&self) -> AdjacentPairs<'_, T> {
+        AdjacentPairs::new(self)
+    }
+
+    /// Returns non-empty iterator over the slice in (non-overlapping) chunks,
+    /// separated by the given predicate.
+    pub const fn chunk_by<P: FnMut(&T, &T) -> bool>(&self, predicate: P) -> ChunkBy<'_, T, P> {
+        ChunkBy::new(self, predicate)
+    }
+
+    /// Returns non-empty iterator over the slice in (non-overlapping) mutable chunks,
+    /// separated by the given predicate.
     pub const fn chunk_by_mut<P: FnMut(&T, &T) -> bool>(
         &mut self,
         predicate: P,
@@ -749,223 +1719,1060 @@ impl<T> NonEmptySlice<T> {
         ChunkByMut::new(self, predicate)
     }
 
-    /// Splits the slice into chunks of `N` items, starting at the beginning of the slice,
-    /// returning the remainder as another slice.
+    /// Returns non-empty iterator over the slice in (non-overlapping) chunks,
+    /// separated by items that match the given predicate.
+    ///
+    /// The matched item is contained at the end of the previous chunk,
+    /// therefore all chunks are non-empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[10, 40, 33, 20]);
+    ///
+    /// let chunks: Vec<_> = non_empty
+    ///     .split_inclusive(|item| item % 3 == 0)
+    ///     .into_iter()
+    ///     .map(|chunk| chunk.as_slice())
+    ///     .collect();
+    ///
+    /// assert_eq!(chunks, [&[10, 40, 33][..], &[20]]);
+    /// ```
+    pub const fn split_inclusive<P: FnMut(&T) -> bool>(
+        &self,
+        predicate: P,
+    ) -> SplitInclusive<'_, T, P> {
+        SplitInclusive::new(self, predicate)
+    }
+
+    /// Returns non-empty iterator over the slice in (non-overlapping) mutable chunks,
+    /// separated by items that match the given predicate.
+    ///
+    /// The matched item is contained at the end of the previous chunk,
+    /// therefore all chunks are non-empty.
+    pub const fn split_inclusive_mut<P: FnMut(&T) -> bool>(
+        &mut self,
+        predicate: P,
+    ) -> SplitInclusiveMut<'_, T, P> {
+        SplitInclusiveMut::new(self, predicate)
+    }
+
+    /// Splits the slice into chunks of `N` items, starting at the beginning of the slice,
+    /// returning the remainder as another slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    pub const fn as_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
+        self.as_slice().as_chunks()
+    }
+
+    /// Splits the slice into mutable chunks of `N` items, starting at the beginning of the slice,
+    /// returning the remainder as another mutable slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    pub const fn as_chunks_mut<const N: usize>(&mut self) -> (&mut [[T; N]], &mut [T]) {
+        self.as_mut_slice().as_chunks_mut()
+    }
+
+    /// Splits the slice into chunks of `N` items, assuming there is no remainder.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the length of the slice is divisible by `N` (and `N != 0`).
+    pub const unsafe fn as_chunks_unchecked<const N: usize>(&self) -> &[[T; N]] {
+        // SAFETY: the caller must ensure that the length of the slice is divisible by `N`
+        // and that `N` is non-zero
+        unsafe { self.as_slice().as_chunks_unchecked() }
+    }
+
+    /// Splits the slice into mutable chunks of `N` items, assuming there is no remainder.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the length of the slice is divisible by `N`.
+    pub const unsafe fn as_chunks_unchecked_mut<const N: usize>(&mut self) -> &mut [[T; N]] {
+        // SAFETY: the caller must ensure that the length of the slice is divisible by `N`
+        // and that `N` is non-zero
+        unsafe { self.as_mut_slice().as_chunks_unchecked_mut() }
+    }
+
+    /// Splits the slice into chunks of `N` items, starting at the end of the slice,
+    /// returning the remainder as another slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    pub const fn as_rchunks<const N: usize>(&self) -> (&[T], &[[T; N]]) {
+        self.as_slice().as_rchunks()
+    }
+
+    /// Splits the mutable slice into mutable chunks of `N` items, starting at the end of the slice,
+    /// returning the remainder as another mutable slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    pub const fn as_rchunks_mut<const N: usize>(&mut self) -> (&mut [T], &mut [[T; N]]) {
+        self.as_mut_slice().as_rchunks_mut()
+    }
+
+    /// Transmutes the slice to slice of another type, ensuring alignment of the types is kept.
+    ///
+    /// See [`align_to`] on slices for more information.
+    ///
+    /// # Safety
+    ///
+    /// This method is essentially [`transmute`] with respect to the items in the middle slice,
+    /// so all the usual caveats pertaining to [`transmute::<T, U>`] apply here.
+    ///
+    /// [`align_to`]: prim@slice#method.align_to
+    /// [`transmute`]: core::mem::transmute
+    /// [`transmute::<T, U>`]: core::mem::transmute
+    pub unsafe fn align_to<U>(&self) -> (&[T], &[U], &[T]) {
+        // SAFETY: the caller must ensure that transmuting `T` to `U` is valid
+        unsafe { self.as_slice().align_to() }
+    }
+
+    /// Transmutes the mutable slice to mutable slice of another type,
+    /// ensuring alignment of the types is kept.
+    ///
+    /// See [`align_to_mut`] on slices for more information.
+    ///
+    /// # Safety
+    ///
+    /// This method is essentially [`transmute`] with respect to the items in the middle slice,
+    /// so all the usual caveats pertaining to [`transmute::<T, U>`] apply here.
+    ///
+    /// [`align_to_mut`]: prim@slice#method.align_to_mut
+    /// [`transmute`]: core::mem::transmute
+    /// [`transmute::<T, U>`]: core::mem::transmute
+    pub unsafe fn align_to_mut<U>(&mut self) -> (&mut [T], &mut [U], &mut [T]) {
+        // SAFETY: the caller must ensure that transmuting `T` to `U` is valid
+        unsafe { self.as_mut_slice().align_to_mut() }
+    }
+
+    /// Splits the slice into two at the given non-zero index.
+    ///
+    /// The index has to be non-zero in order to guarantee non-emptiness of the left slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub const fn split_at(&self, index: Size) -> (&Self, &[T]) {
+        let (left, right) = self.as_slice().split_at(index.get());
+
+        // SAFETY: splitting non-empty slice at non-zero index yields non-empty left slice
+        let left_non_empty = unsafe { Self::from_slice_unchecked(left) };
+
+        (left_non_empty, right)
+    }
+
+    /// Splits the mutable slice into two at the given non-zero index.
+    ///
+    /// The index has to be non-zero in order to guarantee non-emptiness of the left slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub const fn split_at_mut(&mut self, index: Size) -> (&mut Self, &mut [T]) {
+        let (left, right) = self.as_mut_slice().split_at_mut(index.get());
+
+        // SAFETY: splitting non-empty slice at non-zero index yields non-empty left slice
+        let left_non_empty = unsafe { Self::from_mut_slice_unchecked(left) };
+
+        (left_non_empty, right)
+    }
+
+    /// Splits the slice into two at the given non-zero index, without doing any bounds checks.
+    ///
+    /// The index has to be non-zero in order to guarantee non-emptiness of the left slice.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the index is in bounds.
+    pub const unsafe fn split_at_unchecked(&self, index: Size) -> (&Self, &[T]) {
+        // SAFETY: the caller must ensure the index is in bounds
+        let (left, right) = unsafe { self.as_slice().split_at_unchecked(index.get()) };
+
+        // SAFETY: splitting non-empty slice at non-zero index yields non-empty left slice
+        let left_non_empty = unsafe { Self::from_slice_unchecked(left) };
+
+        (left_non_empty, right)
+    }
+
+    /// Splits the mutable slice into two at the given non-zero index,
+    /// without doing any bounds checks.
+    ///
+    /// The index has to be non-zero in order to guarantee non-emptiness of the left slice.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the index is in bounds.
+    pub const unsafe fn split_at_mut_unchecked(&mut self, index: Size) -> (&mut Self, &mut [T]) {
+        // SAFETY: the caller must ensure the index is in bounds
+        let (left, right) = unsafe { self.as_mut_slice().split_at_mut_unchecked(index.get()) };
+
+        // SAFETY: splitting non-empty slice at non-zero index yields non-empty left slice
+        let left_non_empty = unsafe { Self::from_mut_slice_unchecked(left) };
+
+        (left_non_empty, right)
+    }
+
+    /// Splits the slice into two at the given non-zero index, returning [`None`] if out of bounds.
+    ///
+    /// The index has to be non-zero in order to guarantee non-emptiness of the left slice.
+    pub const fn split_at_checked(&self, index: Size) -> Option<(&Self, &[T])> {
+        let Some((left, right)) = self.as_slice().split_at_checked(index.get()) else {
+            return None;
+        };
+
+        // SAFETY: splitting non-empty slice at non-zero index yields non-empty left slice
+        let left_non_empty = unsafe { Self::from_slice_unchecked(left) };
+
+        Some((left_non_empty, right))
+    }
+
+    /// Splits the mutable slice into two at the given non-zero index,
+    /// returning [`None`] if out of bounds.
+    ///
+    /// The index has to be non-zero in order to guarantee non-emptiness of the left slice.
+    pub const fn split_at_mut_checked(&mut self, index: Size) -> Option<(&mut Self, &mut [T])> {
+        let Some((left, right)) = self.as_mut_slice().split_at_mut_checked(index.get()) else {
+            return None;
+        };
+
+        // SAFETY: splitting non-empty slice at non-zero index yields non-empty left slice
+        let left_non_empty = unsafe { Self::from_mut_slice_unchecked(left) };
+
+        Some((left_non_empty, right))
+    }
+
+    /// Returns the non-empty subslice at the given range, or [`None`] if the range
+    /// is out of bounds or the resulting subslice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3, 4]);
+    ///
+    /// let middle = non_empty.get_non_empty(1..3).unwrap();
+    ///
+    /// assert_eq!(middle.as_slice(), [2, 3]);
+    ///
+    /// assert_eq!(middle.get_non_empty(1..).unwrap().as_slice(), [3]);
+    ///
+    /// assert!(non_empty.get_non_empty(2..2).is_none());
+    /// assert!(non_empty.get_non_empty(..5).is_none());
+    /// ```
+    pub fn get_non_empty<I: SliceIndex<[T], Output = [T]>>(&self, index: I) -> Option<&Self> {
+        self.as_slice().get(index).and_then(Self::from_slice)
+    }
+
+    /// Returns the mutable non-empty subslice at the given range, or [`None`] if the range
+    /// is out of bounds or the resulting subslice is empty.
+    pub fn get_non_empty_mut<I: SliceIndex<[T], Output = [T]>>(
+        &mut self,
+        index: I,
+    ) -> Option<&mut Self> {
+        self.as_mut_slice()
+            .get_mut(index)
+            .and_then(Self::from_mut_slice)
+    }
+
+    // NOTE: `Index<Size>` can not be implemented, as it would conflict with `Index<I>`,
+    // since `core` is allowed to implement `SliceIndex<[T]>` for `Size` in the future
+
+    /// Returns the item at the given *one-based* position, or [`None`] if it is out of bounds.
+    ///
+    /// Since the position is non-zero, [`first`] is at position `1`,
+    /// and [`last`] is at position [`len`]; for zero-based indices, use [`get`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    /// use non_zero_size::const_size;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3]);
+    ///
+    /// assert_eq!(non_empty.get_at(const_size!(1)), Some(&1));
+    /// assert_eq!(non_empty.get_at(non_empty.len()), Some(&3));
+    ///
+    /// assert_eq!(non_empty.get_at(const_size!(4)), None);
+    /// ```
+    ///
+    /// [`first`]: Self::first
+    /// [`last`]: Self::last
+    /// [`len`]: Self::len
+    /// [`get`]: prim@slice#method.get
+    #[must_use]
+    pub const fn get_at(&self, position: Size) -> Option<&T> {
+        let index = position.get() - 1;
+
+        if index < self.len().get() {
+            Some(&self.as_slice()[index])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the mutable item at the given *one-based* position,
+    /// or [`None`] if it is out of bounds.
+    ///
+    /// See [`get_at`] for more information.
+    ///
+    /// [`get_at`]: Self::get_at
+    #[must_use]
+    pub const fn get_at_mut(&mut self, position: Size) -> Option<&mut T> {
+        let index = position.get() - 1;
+
+        if index < self.len().get() {
+            Some(&mut self.as_mut_slice()[index])
+        } else {
+            None
+        }
+    }
+
+    // NOTE: other methods are available via deref coercion to `[T]`
+}
+
+impl<T: Clone> NonEmptySlice<T> {
+    /// Clones all items from another non-empty slice into this one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    pub fn clone_from_non_empty_slice(&mut self, other: &Self) {
+        self.as_mut_slice().clone_from_slice(other.as_slice());
+    }
+
+    /// Fills the slice with the given value, cloning it.
+    pub fn fill(&mut self, value: T) {
+        self.as_mut_slice().fill(value);
+    }
+
+    /// Reduces the slice to the single value by repeatedly applying the given function,
+    /// using the clone of the first item as the initial accumulator.
+    ///
+    /// Unlike [`Iterator::reduce`], this never returns [`None`] since the slice is non-empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[13, 42, 69]);
+    ///
+    /// assert_eq!(non_empty.reduce(|maximum, item| maximum.max(*item)), 69);
+    /// ```
+    pub fn reduce<F: FnMut(T, &T) -> T>(&self, function: F) -> T {
+        self.fold_first(T::clone, function)
+    }
+}
+
+impl<T> NonEmptySlice<T> {
+    /// Searches for the first item matching the predicate, returning its index.
+    ///
+    /// The index returned, if any, is always in bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3, 4]);
+    ///
+    /// assert_eq!(non_empty.position(|&item| item % 2 == 0), Some(1));
+    /// assert_eq!(non_empty.position(|&item| item > 4), None);
+    /// ```
+    pub fn position<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+
+    /// Searches for the last item matching the predicate, returning its index.
+    ///
+    /// The index returned, if any, is always in bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3, 4]);
+    ///
+    /// assert_eq!(non_empty.rposition(|&item| item % 2 == 0), Some(3));
+    /// ```
+    pub fn rposition<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<usize> {
+        self.iter().rposition(predicate)
+    }
+
+    /// Applies the function to the items in order, returning the first non-[`None`] result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&["nekit", "13", "42"]);
+    ///
+    /// assert_eq!(non_empty.find_map(|item| item.parse::<u8>().ok()), Some(13));
+    /// ```
+    pub fn find_map<U, F: FnMut(&T) -> Option<U>>(&self, function: F) -> Option<U> {
+        self.iter().find_map(function)
+    }
+}
+
+impl<T: PartialEq> NonEmptySlice<T> {
+    /// Checks whether the slice contains the given value.
+    pub fn contains(&self, value: &T) -> bool {
+        self.as_slice().contains(value)
+    }
+
+    /// Searches for the first occurrence of the given subslice, returning its starting index.
+    ///
+    /// Empty subslices are found at the index zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3, 1, 2]);
+    ///
+    /// assert_eq!(non_empty.find_subslice(&[1, 2]), Some(0));
+    /// assert_eq!(non_empty.find_subslice(&[2, 3, 1]), Some(1));
+    /// assert_eq!(non_empty.find_subslice(&[3, 2]), None);
+    /// ```
+    pub fn find_subslice(&self, needle: &[T]) -> Option<usize> {
+        let Some(needle) = Self::from_slice(needle) else {
+            return Some(0);
+        };
+
+        self.as_slice()
+            .windows(needle.len().get())
+            .position(|window| window == needle.as_slice())
+    }
+
+    /// Searches for the last occurrence of the given subslice, returning its starting index.
+    ///
+    /// Empty subslices are found at the index equal to the length of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3, 1, 2]);
+    ///
+    /// assert_eq!(non_empty.rfind_subslice(&[1, 2]), Some(3));
+    /// ```
+    pub fn rfind_subslice(&self, needle: &[T]) -> Option<usize> {
+        let Some(needle) = Self::from_slice(needle) else {
+            return Some(self.len().get());
+        };
+
+        self.as_slice()
+            .windows(needle.len().get())
+            .rposition(|window| window == needle.as_slice())
+    }
+
+    /// Checks whether the given slice is the prefix of this slice.
+    pub fn starts_with(&self, prefix: &[T]) -> bool {
+        self.as_slice().starts_with(prefix)
+    }
+
+    /// Checks whether the given slice is the suffix of this slice.
+    pub fn ends_with(&self, suffix: &[T]) -> bool {
+        self.as_slice().ends_with(suffix)
+    }
+
+    /// Similar to [`starts_with`], but accepts non-empty prefixes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3]);
+    ///
+    /// assert!(non_empty.starts_with_non_empty(non_empty_slice!(&[1, 2])));
+    /// assert!(!non_empty.starts_with_non_empty(non_empty_slice!(&[2, 3])));
+    /// ```
+    ///
+    /// [`starts_with`]: Self::starts_with
+    pub fn starts_with_non_empty(&self, prefix: &Self) -> bool {
+        self.starts_with(prefix.as_slice())
+    }
+
+    /// Similar to [`ends_with`], but accepts non-empty suffixes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3]);
+    ///
+    /// assert!(non_empty.ends_with_non_empty(non_empty_slice!(&[2, 3])));
+    /// assert!(!non_empty.ends_with_non_empty(non_empty_slice!(&[1, 2])));
+    /// ```
+    ///
+    /// [`ends_with`]: Self::ends_with
+    pub fn ends_with_non_empty(&self, suffix: &Self) -> bool {
+        self.ends_with(suffix.as_slice())
+    }
+
+    /// Returns non-empty iterator over runs of equal items in the slice,
+    /// yielding the first item of each run along with the length of the run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    /// use non_zero_size::const_size;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 1, 2, 3, 3, 3]);
+    ///
+    /// let runs: Vec<_> = non_empty.run_lengths().into_iter().collect();
+    ///
+    /// assert_eq!(
+    ///     runs,
+    ///     [(&1, const_size!(2)), (&2, const_size!(1)), (&3, const_size!(3))]
+    /// );
+    /// ```
+    pub const fn run_lengths(&self) -> RunLengths<'_, T> {
+        RunLengths::new(self)
+    }
+
+    /// Returns the slice with the given prefix removed,
+    /// or [`None`] if the slice does not start with it.
+    pub fn strip_prefix(&self, prefix: &[T]) -> Option<&[T]> {
+        self.as_slice().strip_prefix(prefix)
+    }
+
+    /// Returns the slice with the given suffix removed,
+    /// or [`None`] if the slice does not end with it.
+    pub fn strip_suffix(&self, suffix: &[T]) -> Option<&[T]> {
+        self.as_slice().strip_suffix(suffix)
+    }
+
+    /// Similar to [`strip_prefix`], but returns [`None`] if the remainder would be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3]);
+    ///
+    /// assert_eq!(non_empty.strip_proper_prefix(&[1]).unwrap().as_slice(), [2, 3]);
+    ///
+    /// assert!(non_empty.strip_proper_prefix(&[1, 2, 3]).is_none());
+    /// ```
+    ///
+    /// [`strip_prefix`]: Self::strip_prefix
+    pub fn strip_proper_prefix(&self, prefix: &[T]) -> Option<&Self> {
+        self.strip_prefix(prefix).and_then(Self::from_slice)
+    }
+
+    /// Similar to [`strip_suffix`], but returns [`None`] if the remainder would be empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3]);
+    ///
+    /// assert_eq!(non_empty.strip_proper_suffix(&[3]).unwrap().as_slice(), [1, 2]);
+    ///
+    /// assert!(non_empty.strip_proper_suffix(&[1, 2, 3]).is_none());
+    /// ```
+    ///
+    /// [`strip_suffix`]: Self::strip_suffix
+    pub fn strip_proper_suffix(&self, suffix: &[T]) -> Option<&Self> {
+        self.strip_suffix(suffix).and_then(Self::from_slice)
+    }
+}
+
+impl<T: Ord> NonEmptySlice<T> {
+    /// Reorders the slice such that the item at `index` is at its final sorted position.
+    ///
+    /// Returns the items before `index`, the item at `index` and the items after `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptySlice;
+    ///
+    /// let mut array = [5, 1, 4, 2, 3];
+    ///
+    /// let non_empty = NonEmptySlice::from_mut_slice(&mut array).unwrap();
+    ///
+    /// let (_, median, _) = non_empty.select_nth_unstable(2);
+    ///
+    /// assert_eq!(*median, 3);
+    /// ```
+    pub fn select_nth_unstable(&mut self, index: usize) -> (&mut [T], &mut T, &mut [T]) {
+        self.as_mut_slice().select_nth_unstable(index)
+    }
+}
+
+impl<T: Copy> NonEmptySlice<T> {
+    /// Copies all items from another non-empty slice into this one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    pub const fn copy_from_non_empty_slice(&mut self, other: &Self) {
+        self.as_mut_slice().copy_from_slice(other.as_slice());
+    }
+
+    /// Copies items from the `source` range to the `destination` index within the slice.
+    ///
+    /// The ranges are allowed to overlap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is out of bounds, or if the end of `source`
+    /// is before its start.
+    pub fn copy_within<R: RangeBounds<usize>>(&mut self, source: R, destination: usize) {
+        self.as_mut_slice().copy_within(source, destination);
+    }
+}
+
+impl<T: Copy + Add<Output = T>> NonEmptySlice<T> {
+    /// Sums the items of the slice.
+    ///
+    /// Since the slice is non-empty, no additive identity is required.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// assert_eq!(non_empty_slice!(&[1, 2, 3]).sum(), 6);
+    /// ```
+    pub fn sum(&self) -> T {
+        self.reduce(|total, item| total + *item)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> NonEmptySlice<T> {
+    /// Multiplies the items of the slice.
+    ///
+    /// Since the slice is non-empty, no multiplicative identity is required.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// assert_eq!(non_empty_slice!(&[2, 3, 7]).product(), 42);
+    /// ```
+    pub fn product(&self) -> T {
+        self.reduce(|total, item| total * *item)
+    }
+}
+
+impl NonEmptySlice<f32> {
+    /// Returns the arithmetic mean of the items of the slice.
+    ///
+    /// Since the slice is non-empty, there is no division by zero.
+    #[must_use]
+    pub fn average(&self) -> f32 {
+        self.sum() / self.len().get() as f32
+    }
+}
+
+impl NonEmptySlice<f64> {
+    /// Returns the arithmetic mean of the items of the slice.
+    ///
+    /// Since the slice is non-empty, there is no division by zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// assert_eq!(non_empty_slice!(&[1.0_f64, 2.0, 6.0]).average(), 3.0);
+    /// ```
+    #[must_use]
+    pub fn average(&self) -> f64 {
+        self.sum() / self.len().get() as f64
+    }
+}
+
+type Bytes = [u8];
+
+impl NonEmptyBytes {
+    /// Checks if all bytes in the slice are within the ASCII range.
+    #[must_use]
+    pub const fn is_ascii(&self) -> bool {
+        self.as_slice().is_ascii()
+    }
+
+    /// Checks that the two slices are ASCII case-insensitively equal.
+    #[must_use]
+    pub const fn eq_ignore_ascii_case(&self, other: &Self) -> bool {
+        self.as_slice().eq_ignore_ascii_case(other.as_slice())
+    }
+
+    /// Converts the slice to its ASCII uppercase equivalent in-place.
+    pub const fn make_ascii_uppercase(&mut self) {
+        self.as_mut_slice().make_ascii_uppercase();
+    }
+
+    /// Converts the slice to its ASCII lowercase equivalent in-place.
+    pub const fn make_ascii_lowercase(&mut self) {
+        self.as_mut_slice().make_ascii_lowercase();
+    }
+
+    /// Returns new slice with leading ASCII whitespace bytes removed.
+    #[must_use]
+    pub const fn trim_ascii_start(&self) -> &Bytes {
+        self.as_slice().trim_ascii_start()
+    }
+
+    /// Returns new slice with trailing ASCII whitespace bytes removed.
+    #[must_use]
+    pub const fn trim_ascii_end(&self) -> &Bytes {
+        self.as_slice().trim_ascii_end()
+    }
+
+    /// Returns new slice with leading and trailing ASCII whitespace bytes removed.
+    #[must_use]
+    pub const fn trim_ascii(&self) -> &Bytes {
+        self.as_slice().trim_ascii()
+    }
+
+    /// Similar to [`trim_ascii_start`], but returns [`None`] if trimming would empty the slice.
     ///
-    /// # Panics
+    /// [`trim_ascii_start`]: Self::trim_ascii_start
+    #[must_use]
+    pub const fn trim_ascii_start_non_empty(&self) -> Option<&Self> {
+        Self::from_slice(self.trim_ascii_start())
+    }
+
+    /// Similar to [`trim_ascii_end`], but returns [`None`] if trimming would empty the slice.
     ///
-    /// Panics if `N` is zero.
-    pub const fn as_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
-        self.as_slice().as_chunks()
+    /// [`trim_ascii_end`]: Self::trim_ascii_end
+    #[must_use]
+    pub const fn trim_ascii_end_non_empty(&self) -> Option<&Self> {
+        Self::from_slice(self.trim_ascii_end())
     }
 
-    /// Splits the slice into mutable chunks of `N` items, starting at the beginning of the slice,
-    /// returning the remainder as another mutable slice.
+    /// Similar to [`trim_ascii`], but returns [`None`] if trimming would empty the slice.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// Panics if `N` is zero.
-    pub const fn as_chunks_mut<const N: usize>(&mut self) -> (&mut [[T; N]], &mut [T]) {
-        self.as_mut_slice().as_chunks_mut()
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    ///
+    /// let bytes = NonEmptyBytes::from_slice(b"  nekit  ").unwrap();
+    ///
+    /// assert_eq!(bytes.trim_ascii_non_empty().unwrap(), b"nekit");
+    ///
+    /// let blank = NonEmptyBytes::from_slice(b"   ").unwrap();
+    ///
+    /// assert!(blank.trim_ascii_non_empty().is_none());
+    /// ```
+    ///
+    /// [`trim_ascii`]: Self::trim_ascii
+    #[must_use]
+    pub const fn trim_ascii_non_empty(&self) -> Option<&Self> {
+        Self::from_slice(self.trim_ascii())
     }
 
-    /// Splits the slice into chunks of `N` items, assuming there is no remainder.
+    /// Similar to [`trim_ascii_non_empty`], but returns the original slice
+    /// if trimming would empty it.
     ///
-    /// # Safety
+    /// # Examples
     ///
-    /// The caller must ensure that the length of the slice is divisible by `N` (and `N != 0`).
-    pub const unsafe fn as_chunks_unchecked<const N: usize>(&self) -> &[[T; N]] {
-        // SAFETY: the caller must ensure that the length of the slice is divisible by `N`
-        // and that `N` is non-zero
-        unsafe { self.as_slice().as_chunks_unchecked() }
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    ///
+    /// let bytes = NonEmptyBytes::from_slice(b"  nekit  ").unwrap();
+    ///
+    /// assert_eq!(bytes.trim_ascii_or_self(), b"nekit");
+    ///
+    /// let blank = NonEmptyBytes::from_slice(b"   ").unwrap();
+    ///
+    /// assert_eq!(blank.trim_ascii_or_self(), b"   ");
+    /// ```
+    ///
+    /// [`trim_ascii_non_empty`]: Self::trim_ascii_non_empty
+    #[must_use]
+    pub const fn trim_ascii_or_self(&self) -> &Self {
+        match self.trim_ascii_non_empty() {
+            Some(trimmed) => trimmed,
+            None => self,
+        }
     }
 
-    /// Splits the slice into mutable chunks of `N` items, assuming there is no remainder.
+    /// Searches for the first occurrence of the given byte, returning its index.
     ///
-    /// # Safety
+    /// # Examples
     ///
-    /// The caller must ensure that the length of the slice is divisible by `N`.
-    pub const unsafe fn as_chunks_unchecked_mut<const N: usize>(&mut self) -> &mut [[T; N]] {
-        // SAFETY: the caller must ensure that the length of the slice is divisible by `N`
-        // and that `N` is non-zero
-        unsafe { self.as_mut_slice().as_chunks_unchecked_mut() }
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    ///
+    /// let bytes = NonEmptyBytes::from_slice(b"key=value").unwrap();
+    ///
+    /// assert_eq!(bytes.find_byte(b'='), Some(3));
+    /// assert_eq!(bytes.find_byte(b'&'), None);
+    /// ```
+    #[must_use]
+    pub fn find_byte(&self, byte: u8) -> Option<usize> {
+        search::find_byte(self.as_slice(), byte)
     }
 
-    /// Splits the slice into chunks of `N` items, starting at the end of the slice,
-    /// returning the remainder as another slice.
+    /// Searches for the last occurrence of the given byte, returning its index.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// Panics if `N` is zero.
-    pub const fn as_rchunks<const N: usize>(&self) -> (&[T], &[[T; N]]) {
-        self.as_slice().as_rchunks()
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    ///
+    /// let bytes = NonEmptyBytes::from_slice(b"a.b.c").unwrap();
+    ///
+    /// assert_eq!(bytes.rfind_byte(b'.'), Some(3));
+    /// ```
+    #[must_use]
+    pub fn rfind_byte(&self, byte: u8) -> Option<usize> {
+        search::rfind_byte(self.as_slice(), byte)
     }
 
-    /// Splits the mutable slice into mutable chunks of `N` items, starting at the end of the slice,
-    /// returning the remainder as another mutable slice.
+    /// Searches for the first occurrence of any of the given bytes, returning its index.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// Panics if `N` is zero.
-    pub const fn as_rchunks_mut<const N: usize>(&mut self) -> (&mut [T], &mut [[T; N]]) {
-        self.as_mut_slice().as_rchunks_mut()
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    ///
+    /// let bytes = NonEmptyBytes::from_slice(b"key=value;next").unwrap();
+    ///
+    /// assert_eq!(bytes.find_any_of([b';', b'=']), Some(3));
+    /// assert_eq!(bytes.find_any_of([]), None);
+    /// ```
+    #[must_use]
+    pub fn find_any_of<const N: usize>(&self, bytes: [u8; N]) -> Option<usize> {
+        search::find_any_of(self.as_slice(), &bytes)
     }
 
-    /// Splits the slice into two at the given non-zero index.
+    /// Returns non-empty iterator over the subslices separated by the given byte.
     ///
-    /// The index has to be non-zero in order to guarantee non-emptiness of the left slice.
+    /// The separator is not included in the subslices, which may be empty.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// Panics if the index is out of bounds.
-    pub const fn split_at(&self, index: Size) -> (&Self, &[T]) {
-        let (left, right) = self.as_slice().split_at(index.get());
-
-        // SAFETY: splitting non-empty slice at non-zero index yields non-empty left slice
-        let left_non_empty = unsafe { Self::from_slice_unchecked(left) };
-
-        (left_non_empty, right)
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    ///
+    /// let bytes = NonEmptyBytes::from_slice(b"a,b,,c").unwrap();
+    ///
+    /// let parts: Vec<&[u8]> = bytes.split_on_byte(b',').into_iter().collect();
+    ///
+    /// assert_eq!(parts, [&b"a"[..], b"b", b"", b"c"]);
+    /// ```
+    pub const fn split_on_byte(&self, byte: u8) -> SplitOnByte<'_> {
+        SplitOnByte::new(self, byte)
     }
 
-    /// Splits the mutable slice into two at the given non-zero index.
+    /// Takes the first `count` bytes, returning them along with the rest of the bytes.
     ///
-    /// The index has to be non-zero in order to guarantee non-emptiness of the left slice.
+    /// If there are less than `count` bytes, [`None`] is returned.
     ///
-    /// # Panics
+    /// This is [`split_at_checked`] with parser-friendly naming.
     ///
-    /// Panics if the index is out of bounds.
-    pub const fn split_at_mut(&mut self, index: Size) -> (&mut Self, &mut [T]) {
-        let (left, right) = self.as_mut_slice().split_at_mut(index.get());
-
-        // SAFETY: splitting non-empty slice at non-zero index yields non-empty left slice
-        let left_non_empty = unsafe { Self::from_mut_slice_unchecked(left) };
-
-        (left_non_empty, right)
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    /// use non_zero_size::const_size;
+    ///
+    /// let input = NonEmptyBytes::from_slice(b"GET /").unwrap();
+    ///
+    /// let (method, rest) = input.take(const_size!(3)).unwrap();
+    ///
+    /// assert_eq!(method, b"GET");
+    /// assert_eq!(rest, b" /");
+    ///
+    /// assert!(input.take(const_size!(6)).is_none());
+    /// ```
+    ///
+    /// [`split_at_checked`]: Self::split_at_checked
+    #[must_use]
+    pub const fn take(&self, count: Size) -> Option<(&Self, &Bytes)> {
+        self.split_at_checked(count)
     }
 
-    /// Splits the slice into two at the given non-zero index, without doing any bounds checks.
+    /// Takes the last `count` bytes, returning them along with the rest of the bytes.
     ///
-    /// The index has to be non-zero in order to guarantee non-emptiness of the left slice.
+    /// If there are less than `count` bytes, [`None`] is returned.
     ///
-    /// # Safety
+    /// # Examples
     ///
-    /// The caller must ensure that the index is in bounds.
-    pub const unsafe fn split_at_unchecked(&self, index: Size) -> (&Self, &[T]) {
-        // SAFETY: the caller must ensure the index is in bounds
-        let (left, right) = unsafe { self.as_slice().split_at_unchecked(index.get()) };
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    /// use non_zero_size::const_size;
+    ///
+    /// let input = NonEmptyBytes::from_slice(b"nekit\r\n").unwrap();
+    ///
+    /// let (rest, ending) = input.take_last(const_size!(2)).unwrap();
+    ///
+    /// assert_eq!(rest, b"nekit");
+    /// assert_eq!(ending, b"\r\n");
+    /// ```
+    #[must_use]
+    pub const fn take_last(&self, count: Size) -> Option<(&Bytes, &Self)> {
+        let Some(index) = self.len().get().checked_sub(count.get()) else {
+            return None;
+        };
 
-        // SAFETY: splitting non-empty slice at non-zero index yields non-empty left slice
-        let left_non_empty = unsafe { Self::from_slice_unchecked(left) };
+        let (rest, taken) = self.as_slice().split_at(index);
 
-        (left_non_empty, right)
+        // SAFETY: `taken` contains `count` bytes, which is non-zero
+        Some((rest, unsafe { Self::from_slice_unchecked(taken) }))
     }
 
-    /// Splits the mutable slice into two at the given non-zero index,
-    /// without doing any bounds checks.
+    /// Reads the first byte, returning it along with the rest of the bytes.
     ///
-    /// The index has to be non-zero in order to guarantee non-emptiness of the left slice.
+    /// This method is infallible, since the bytes are non-empty.
     ///
-    /// # Safety
+    /// # Examples
     ///
-    /// The caller must ensure that the index is in bounds.
-    pub const unsafe fn split_at_mut_unchecked(&mut self, index: Size) -> (&mut Self, &mut [T]) {
-        // SAFETY: the caller must ensure the index is in bounds
-        let (left, right) = unsafe { self.as_mut_slice().split_at_mut_unchecked(index.get()) };
-
-        // SAFETY: splitting non-empty slice at non-zero index yields non-empty left slice
-        let left_non_empty = unsafe { Self::from_mut_slice_unchecked(left) };
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    ///
+    /// let input = NonEmptyBytes::from_slice(b"\x01\x00\x02\x00\x00\x03").unwrap();
+    ///
+    /// let (tag, rest) = input.read_u8();
+    ///
+    /// assert_eq!(tag, 1);
+    ///
+    /// let rest = NonEmptyBytes::from_slice(rest).unwrap();
+    ///
+    /// let (length, rest) = rest.read_u16_be().unwrap();
+    ///
+    /// assert_eq!(length, 2);
+    ///
+    /// let rest = NonEmptyBytes::from_slice(rest).unwrap();
+    ///
+    /// assert_eq!(rest.read_u32_be(), None);
+    /// ```
+    #[must_use]
+    pub const fn read_u8(&self) -> (u8, &Bytes) {
+        let (&byte, rest) = self.split_first();
 
-        (left_non_empty, right)
+        (byte, rest)
     }
 
-    /// Splits the slice into two at the given non-zero index, returning [`None`] if out of bounds.
+    /// Reads [`u16`] from the first 2 bytes in little-endian byte order,
+    /// returning it along with the rest of the bytes.
     ///
-    /// The index has to be non-zero in order to guarantee non-emptiness of the left slice.
-    pub const fn split_at_checked(&self, index: Size) -> Option<(&Self, &[T])> {
-        let Some((left, right)) = self.as_slice().split_at_checked(index.get()) else {
+    /// If there are less than 2 bytes, [`None`] is returned.
+    #[must_use]
+    pub const fn read_u16_le(&self) -> Option<(u16, &Bytes)> {
+        let Some((&chunk, rest)) = self.split_first_chunk() else {
             return None;
         };
 
-        // SAFETY: splitting non-empty slice at non-zero index yields non-empty left slice
-        let left_non_empty = unsafe { Self::from_slice_unchecked(left) };
-
-        Some((left_non_empty, right))
+        Some((u16::from_le_bytes(chunk), rest))
     }
 
-    /// Splits the mutable slice into two at the given non-zero index,
-    /// returning [`None`] if out of bounds.
+    /// Reads [`u16`] from the first 2 bytes in big-endian byte order,
+    /// returning it along with the rest of the bytes.
     ///
-    /// The index has to be non-zero in order to guarantee non-emptiness of the left slice.
-    pub const fn split_at_mut_checked(&mut self, index: Size) -> Option<(&mut Self, &mut [T])> {
-        let Some((left, right)) = self.as_mut_slice().split_at_mut_checked(index.get()) else {
+    /// If there are less than 2 bytes, [`None`] is returned.
+    #[must_use]
+    pub const fn read_u16_be(&self) -> Option<(u16, &Bytes)> {
+        let Some((&chunk, rest)) = self.split_first_chunk() else {
             return None;
         };
 
-        // SAFETY: splitting non-empty slice at non-zero index yields non-empty left slice
-        let left_non_empty = unsafe { Self::from_mut_slice_unchecked(left) };
-
-        Some((left_non_empty, right))
+        Some((u16::from_be_bytes(chunk), rest))
     }
 
-    // NOTE: other methods are available via deref coercion to `[T]`
-}
-
-impl<T: Clone> NonEmptySlice<T> {
-    /// Clones all items from another non-empty slice into this one.
+    /// Reads [`u32`] from the first 4 bytes in little-endian byte order,
+    /// returning it along with the rest of the bytes.
     ///
-    /// # Panics
-    ///
-    /// Panics if the slices have different lengths.
-    pub fn clone_from_non_empty_slice(&mut self, other: &Self) {
-        self.as_mut_slice().clone_from_slice(other.as_slice());
-    }
-}
-
-type Bytes = [u8];
-
-impl NonEmptyBytes {
-    /// Checks if all bytes in the slice are within the ASCII range.
+    /// If there are less than 4 bytes, [`None`] is returned.
     #[must_use]
-    pub const fn is_ascii(&self) -> bool {
-        self.as_slice().is_ascii()
-    }
+    pub const fn read_u32_le(&self) -> Option<(u32, &Bytes)> {
+        let Some((&chunk, rest)) = self.split_first_chunk() else {
+            return None;
+        };
 
-    /// Checks that the two slices are ASCII case-insensitively equal.
-    #[must_use]
-    pub const fn eq_ignore_ascii_case(&self, other: &Self) -> bool {
-        self.as_slice().eq_ignore_ascii_case(other.as_slice())
+        Some((u32::from_le_bytes(chunk), rest))
     }
 
-    /// Converts the slice to its ASCII uppercase equivalent in-place.
-    pub const fn make_ascii_uppercase(&mut self) {
-        self.as_mut_slice().make_ascii_uppercase();
-    }
+    /// Reads [`u32`] from the first 4 bytes in big-endian byte order,
+    /// returning it along with the rest of the bytes.
+    ///
+    /// If there are less than 4 bytes, [`None`] is returned.
+    #[must_use]
+    pub const fn read_u32_be(&self) -> Option<(u32, &Bytes)> {
+        let Some((&chunk, rest)) = self.split_first_chunk() else {
+            return None;
+        };
 
-    /// Converts the slice to its ASCII lowercase equivalent in-place.
-    pub const fn make_ascii_lowercase(&mut self) {
-        self.as_mut_slice().make_ascii_lowercase();
+        Some((u32::from_be_bytes(chunk), rest))
     }
 
-    /// Returns new slice with leading ASCII whitespace bytes removed.
+    /// Reads [`u64`] from the first 8 bytes in little-endian byte order,
+    /// returning it along with the rest of the bytes.
+    ///
+    /// If there are less than 8 bytes, [`None`] is returned.
     #[must_use]
-    pub const fn trim_ascii_start(&self) -> &Bytes {
-        self.as_slice().trim_ascii_start()
-    }
+    pub const fn read_u64_le(&self) -> Option<(u64, &Bytes)> {
+        let Some((&chunk, rest)) = self.split_first_chunk() else {
+            return None;
+        };
 
-    /// Returns new slice with trailing ASCII whitespace bytes removed.
-    #[must_use]
-    pub const fn trim_ascii_end(&self) -> &Bytes {
-        self.as_slice().trim_ascii_end()
+        Some((u64::from_le_bytes(chunk), rest))
     }
 
-    /// Returns new slice with leading and trailing ASCII whitespace bytes removed.
+    /// Reads [`u64`] from the first 8 bytes in big-endian byte order,
+    /// returning it along with the rest of the bytes.
+    ///
+    /// If there are less than 8 bytes, [`None`] is returned.
     #[must_use]
-    pub const fn trim_ascii(&self) -> &Bytes {
-        self.as_slice().trim_ascii()
+    pub const fn read_u64_be(&self) -> Option<(u64, &Bytes)> {
+        let Some((&chunk, rest)) = self.split_first_chunk() else {
+            return None;
+        };
+
+        Some((u64::from_be_bytes(chunk), rest))
     }
 
     /// Returns non-empty iterators that produce escaped version of the slice,
     /// treating it as ASCII string.
+    ///
+    /// The returned value also implements [`Display`], writing the escaped string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    ///
+    /// let bytes = NonEmptyBytes::from_slice(b"nekit\n").unwrap();
+    ///
+    /// assert_eq!(bytes.escape_ascii().to_string(), "nekit\\n");
+    /// ```
+    ///
+    /// [`Display`]: core::fmt::Display
     #[must_use]
     pub const fn escape_ascii(&self) -> EscapeAscii<'_> {
         EscapeAscii::new(self)
@@ -0,0 +1,508 @@
+//! Non-empty [`SmallVec<A>`].
+
+#[cfg(not(feature = "smallvec"))]
+compile_error!("expected `smallvec` to be enabled");
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("expected either `std` or `alloc` to be enabled");
+
+use core::{
+    borrow::{Borrow, BorrowMut},
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+};
+
+use non_empty_iter::{
+    FromNonEmptyIterator, IntoNonEmptyIterator, NonEmptyAdapter, NonEmptyIterator,
+};
+use non_zero_size::Size;
+use smallvec::{Array, IntoIter, SmallVec};
+use thiserror::Error;
+
+use crate::{format, slice::NonEmptySlice, vec::NonEmptyVec};
+
+/// The error message used when the small vector is empty.
+pub const EMPTY_SMALL_VEC: &str = "the small vector is empty";
+
+/// Similar to [`EmptyVec<T>`], but holds the empty small vector provided.
+///
+/// [`EmptyVec<T>`]: crate::vec::EmptyVec
+#[derive(Error)]
+#[error("{EMPTY_SMALL_VEC}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_slice::small_vec),
+        help("make sure the small vector is non-empty")
+    )
+)]
+pub struct EmptySmallVec<A: Array> {
+    small_vec: SmallVec<A>,
+}
+
+format::debug!(EmptySmallVec<A: Array>, small_vec);
+
+impl<A: Array> EmptySmallVec<A> {
+    // NOTE: this is private to prevent creating this error with non-empty small vectors
+    pub(crate) const fn new(small_vec: SmallVec<A>) -> Self {
+        Self { small_vec }
+    }
+
+    /// Returns the contained empty small vector.
+    #[must_use]
+    pub fn get(self) -> SmallVec<A> {
+        self.small_vec
+    }
+}
+
+/// Represents non-empty [`SmallVec<A>`] values.
+#[repr(transparent)]
+pub struct NonEmptySmallVec<A: Array> {
+    inner: SmallVec<A>,
+}
+
+impl<A: Array> fmt::Debug for NonEmptySmallVec<A>
+where
+    A::Item: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct(stringify!(NonEmptySmallVec))
+            .field(stringify!(inner), self.as_small_vec())
+            .finish()
+    }
+}
+
+impl<A: Array> Clone for NonEmptySmallVec<A>
+where
+    A::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        // SAFETY: the small vector is non-empty by construction
+        unsafe { Self::new_unchecked(self.as_small_vec().clone()) }
+    }
+}
+
+impl<A: Array> PartialEq for NonEmptySmallVec<A>
+where
+    A::Item: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_small_vec().eq(other.as_small_vec())
+    }
+}
+
+impl<A: Array> Eq for NonEmptySmallVec<A> where A::Item: Eq {}
+
+impl<A: Array> PartialOrd for NonEmptySmallVec<A>
+where
+    A::Item: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_small_vec().partial_cmp(other.as_small_vec())
+    }
+}
+
+impl<A: Array> Ord for NonEmptySmallVec<A>
+where
+    A::Item: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_small_vec().cmp(other.as_small_vec())
+    }
+}
+
+impl<A: Array> Hash for NonEmptySmallVec<A>
+where
+    A::Item: Hash,
+{
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.as_small_vec().hash(hasher);
+    }
+}
+
+impl<A: Array> Borrow<NonEmptySlice<A::Item>> for NonEmptySmallVec<A> {
+    fn borrow(&self) -> &NonEmptySlice<A::Item> {
+        self.as_non_empty_slice()
+    }
+}
+
+impl<A: Array> BorrowMut<NonEmptySlice<A::Item>> for NonEmptySmallVec<A> {
+    fn borrow_mut(&mut self) -> &mut NonEmptySlice<A::Item> {
+        self.as_non_empty_mut_slice()
+    }
+}
+
+impl<A: Array> Borrow<[A::Item]> for NonEmptySmallVec<A> {
+    fn borrow(&self) -> &[A::Item] {
+        self.as_slice()
+    }
+}
+
+impl<A: Array> BorrowMut<[A::Item]> for NonEmptySmallVec<A> {
+    fn borrow_mut(&mut self) -> &mut [A::Item] {
+        self.as_mut_slice()
+    }
+}
+
+impl<A: Array> TryFrom<SmallVec<A>> for NonEmptySmallVec<A> {
+    type Error = EmptySmallVec<A>;
+
+    fn try_from(small_vec: SmallVec<A>) -> Result<Self, Self::Error> {
+        Self::new(small_vec)
+    }
+}
+
+impl<A: Array> From<NonEmptySmallVec<A>> for SmallVec<A> {
+    fn from(non_empty: NonEmptySmallVec<A>) -> Self {
+        non_empty.into_small_vec()
+    }
+}
+
+impl<A: Array> From<NonEmptyVec<A::Item>> for NonEmptySmallVec<A> {
+    fn from(non_empty: NonEmptyVec<A::Item>) -> Self {
+        Self::from_non_empty_vec(non_empty)
+    }
+}
+
+impl<A: Array> From<NonEmptySmallVec<A>> for NonEmptyVec<A::Item> {
+    fn from(non_empty: NonEmptySmallVec<A>) -> Self {
+        non_empty.into_non_empty_vec()
+    }
+}
+
+impl<A: Array> From<&NonEmptySlice<A::Item>> for NonEmptySmallVec<A>
+where
+    A::Item: Clone,
+{
+    fn from(non_empty: &NonEmptySlice<A::Item>) -> Self {
+        Self::from_non_empty_slice(non_empty)
+    }
+}
+
+impl<A: Array> AsRef<Self> for NonEmptySmallVec<A> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl<A: Array> AsMut<Self> for NonEmptySmallVec<A> {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl<A: Array> AsRef<NonEmptySlice<A::Item>> for NonEmptySmallVec<A> {
+    fn as_ref(&self) -> &NonEmptySlice<A::Item> {
+        self.as_non_empty_slice()
+    }
+}
+
+impl<A: Array> AsMut<NonEmptySlice<A::Item>> for NonEmptySmallVec<A> {
+    fn as_mut(&mut self) -> &mut NonEmptySlice<A::Item> {
+        self.as_non_empty_mut_slice()
+    }
+}
+
+impl<A: Array> AsRef<[A::Item]> for NonEmptySmallVec<A> {
+    fn as_ref(&self) -> &[A::Item] {
+        self.as_slice()
+    }
+}
+
+impl<A: Array> AsMut<[A::Item]> for NonEmptySmallVec<A> {
+    fn as_mut(&mut self) -> &mut [A::Item] {
+        self.as_mut_slice()
+    }
+}
+
+impl<A: Array> Deref for NonEmptySmallVec<A> {
+    type Target = NonEmptySlice<A::Item>;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_empty_slice()
+    }
+}
+
+impl<A: Array> DerefMut for NonEmptySmallVec<A> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_non_empty_mut_slice()
+    }
+}
+
+impl<A: Array> NonEmptySmallVec<A> {
+    /// Constructs [`Self`], provided that the [`SmallVec<A>`] provided is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptySmallVec<A>`] if the provided small vector is empty.
+    pub fn new(small_vec: SmallVec<A>) -> Result<Self, EmptySmallVec<A>> {
+        if small_vec.is_empty() {
+            return Err(EmptySmallVec::new(small_vec));
+        }
+
+        // SAFETY: the small vector is non-empty at this point
+        Ok(unsafe { Self::new_unchecked(small_vec) })
+    }
+
+    /// Constructs [`Self`] without checking that the [`SmallVec<A>`] is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the small vector is non-empty.
+    #[must_use]
+    pub const unsafe fn new_unchecked(inner: SmallVec<A>) -> Self {
+        Self { inner }
+    }
+
+    /// Constructs [`Self`] containing the single value provided.
+    pub fn single(value: A::Item) -> Self {
+        let mut small_vec = SmallVec::new();
+
+        small_vec.push(value);
+
+        // SAFETY: non-empty construction
+        unsafe { Self::new_unchecked(small_vec) }
+    }
+
+    /// Constructs [`Self`] from [`NonEmptyVec<T>`].
+    ///
+    /// The allocation of the vector is reused only if its capacity does not fit inline;
+    /// otherwise, the items are moved inline and the allocation is freed.
+    pub fn from_non_empty_vec(non_empty: NonEmptyVec<A::Item>) -> Self {
+        // SAFETY: the vector is non-empty by construction, so is the small vector
+        unsafe { Self::new_unchecked(SmallVec::from_vec(non_empty.into_vec())) }
+    }
+
+    /// Converts [`Self`] into [`NonEmptyVec<T>`].
+    pub fn into_non_empty_vec(self) -> NonEmptyVec<A::Item> {
+        // SAFETY: the small vector is non-empty by construction, so is the vector
+        unsafe { NonEmptyVec::new_unchecked(self.into_small_vec().into_vec()) }
+    }
+
+    /// Returns the contained slice reference as [`NonEmptySlice<T>`].
+    #[must_use]
+    pub fn as_non_empty_slice(&self) -> &NonEmptySlice<A::Item> {
+        // SAFETY: the slice is non-empty by construction
+        unsafe { NonEmptySlice::from_slice_unchecked(self.as_slice()) }
+    }
+
+    /// Returns the contained slice reference as mutable [`NonEmptySlice<T>`].
+    #[must_use]
+    pub fn as_non_empty_mut_slice(&mut self) -> &mut NonEmptySlice<A::Item> {
+        // SAFETY: the slice is non-empty by construction
+        unsafe { NonEmptySlice::from_mut_slice_unchecked(self.as_mut_slice()) }
+    }
+
+    /// Extracts the slice containing the entire small vector.
+    #[must_use]
+    pub fn as_slice(&self) -> &[A::Item] {
+        self.as_small_vec().as_slice()
+    }
+
+    /// Extracts the mutable slice containing the entire small vector.
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut [A::Item] {
+        // SAFETY: getting mutable slice can not make the small vector empty
+        unsafe { self.as_mut_small_vec().as_mut_slice() }
+    }
+
+    /// Returns the contained [`SmallVec<A>`] behind immutable reference.
+    #[must_use]
+    pub const fn as_small_vec(&self) -> &SmallVec<A> {
+        &self.inner
+    }
+
+    /// Returns the contained [`SmallVec<A>`] behind mutable reference.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the returned small vector remains non-empty.
+    #[must_use]
+    pub const unsafe fn as_mut_small_vec(&mut self) -> &mut SmallVec<A> {
+        &mut self.inner
+    }
+
+    /// Returns the contained [`SmallVec<A>`].
+    #[must_use]
+    pub fn into_small_vec(self) -> SmallVec<A> {
+        self.inner
+    }
+
+    /// Checks if the small vector is empty. Always returns [`false`].
+    ///
+    /// This method is marked as deprecated since the small vector is never empty.
+    #[must_use]
+    #[deprecated = "this small vector is never empty"]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the length of the small vector as [`Size`].
+    #[must_use]
+    pub fn len(&self) -> Size {
+        self.as_non_empty_slice().len()
+    }
+
+    /// Returns the capacity of the small vector as [`Size`].
+    #[must_use]
+    pub fn capacity(&self) -> Size {
+        let capacity = self.as_small_vec().capacity();
+
+        // SAFETY: non-empty small vector implies non-zero capacity
+        unsafe { Size::new_unchecked(capacity) }
+    }
+
+    /// Checks whether the data has spilled into the separate heap-allocated buffer.
+    #[must_use]
+    pub fn spilled(&self) -> bool {
+        self.as_small_vec().spilled()
+    }
+
+    /// Appends the given value to the end of the small vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics on capacity overflow.
+    pub fn push(&mut self, value: A::Item) {
+        // SAFETY: pushing can not make the small vector empty
+        unsafe {
+            self.as_mut_small_vec().push(value);
+        }
+    }
+
+    /// Inserts the given value at the specified index, shifting all items after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: A::Item) {
+        // SAFETY: inserting can not make the small vector empty
+        unsafe {
+            self.as_mut_small_vec().insert(index, value);
+        }
+    }
+
+    /// Shortens the small vector, keeping the first `len` items and dropping the rest.
+    pub fn truncate(&mut self, len: Size) {
+        // SAFETY: length provided is non-zero, so truncating can not make the small vector empty
+        unsafe {
+            self.as_mut_small_vec().truncate(len.get());
+        }
+    }
+
+    /// Checks whether the small vector is almost empty, meaning it only contains one value.
+    #[must_use]
+    pub fn next_empty(&self) -> bool {
+        self.len() == Size::MIN
+    }
+
+    /// The negated version of [`next_empty`].
+    ///
+    /// [`next_empty`]: Self::next_empty
+    #[must_use]
+    pub fn next_non_empty(&self) -> bool {
+        !self.next_empty()
+    }
+
+    /// Removes the last item from the small vector and returns it,
+    /// or [`None`] if the small vector would become empty.
+    pub fn pop(&mut self) -> Option<A::Item> {
+        self.next_non_empty()
+            // SAFETY: popping only if the small vector would remain non-empty
+            .then(|| unsafe { self.as_mut_small_vec().pop() })
+            .flatten()
+    }
+
+    /// Removes and returns the item at the given index within the small vector,
+    /// shifting all items after it to the left.
+    ///
+    /// Returns [`None`] if the small vector would become empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`, provided the small vector would not become empty.
+    pub fn remove(&mut self, index: usize) -> Option<A::Item> {
+        self.next_non_empty()
+            // SAFETY: removing only if the small vector would remain non-empty
+            .then(|| unsafe { self.as_mut_small_vec().remove(index) })
+    }
+
+    /// Removes and returns the item at the given index within the small vector,
+    /// replacing it with the last item of the small vector.
+    ///
+    /// Returns [`None`] if the small vector would become empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`, provided the small vector would not become empty.
+    pub fn swap_remove(&mut self, index: usize) -> Option<A::Item> {
+        self.next_non_empty()
+            // SAFETY: swap-removing only if the small vector would remain non-empty
+            .then(|| unsafe { self.as_mut_small_vec().swap_remove(index) })
+    }
+}
+
+impl<A: Array> NonEmptySmallVec<A>
+where
+    A::Item: Clone,
+{
+    /// Constructs [`Self`] from [`NonEmptySlice<T>`] via cloning.
+    pub fn from_non_empty_slice(non_empty: &NonEmptySlice<A::Item>) -> Self {
+        // SAFETY: the slice is non-empty by construction
+        unsafe { Self::new_unchecked(SmallVec::from(non_empty.as_slice())) }
+    }
+
+    /// Extends the small vector by cloning all items from the provided value that can be
+    /// converted to [`[T]`](prim@slice).
+    pub fn extend_from<S: AsRef<[A::Item]>>(&mut self, slice: S) {
+        // SAFETY: extending can not make the small vector empty
+        unsafe {
+            self.as_mut_small_vec()
+                .extend(slice.as_ref().iter().cloned());
+        }
+    }
+}
+
+impl<A: Array> Extend<A::Item> for NonEmptySmallVec<A> {
+    fn extend<I: IntoIterator<Item = A::Item>>(&mut self, iterable: I) {
+        // SAFETY: extending can not make the small vector empty
+        unsafe {
+            self.as_mut_small_vec().extend(iterable);
+        }
+    }
+}
+
+impl<A: Array> IntoIterator for NonEmptySmallVec<A> {
+    type Item = A::Item;
+
+    type IntoIter = IntoIter<A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_small_vec().into_iter()
+    }
+}
+
+impl<A: Array> IntoNonEmptyIterator for NonEmptySmallVec<A> {
+    type IntoNonEmptyIter = NonEmptyAdapter<IntoIter<A>>;
+
+    fn into_non_empty_iter(self) -> Self::IntoNonEmptyIter {
+        // SAFETY: the small vector is non-empty by construction
+        unsafe { NonEmptyAdapter::new(self.into_iter()) }
+    }
+}
+
+impl<A: Array> FromNonEmptyIterator<A::Item> for NonEmptySmallVec<A> {
+    fn from_non_empty_iter<I: IntoNonEmptyIterator<Item = A::Item>>(iterable: I) -> Self {
+        let (item, iterator) = iterable.into_non_empty_iter().consume();
+
+        let mut output = Self::single(item);
+
+        output.extend(iterator);
+
+        output
+    }
+}
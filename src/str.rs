@@ -0,0 +1,391 @@
+//! Non-empty [`str`].
+
+use core::{
+    fmt,
+    ops::{Deref, DerefMut},
+    ptr,
+    str::{Chars, Utf8Error, from_utf8, from_utf8_mut, from_utf8_unchecked},
+};
+
+use non_empty_iter::NonEmptyAdapter;
+use non_zero_size::Size;
+use thiserror::Error;
+
+use crate::slice::NonEmptyBytes;
+
+/// The error message used when the string is empty.
+pub const EMPTY_STR: &str = "the string is empty";
+
+/// Represents errors returned when received strings are empty.
+#[derive(Debug, Error)]
+#[error("{EMPTY_STR}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(code(non_empty_slice::str), help("make sure the string is non-empty"))
+)]
+pub struct EmptyStr;
+
+/// Represents non-empty string slices.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptyStr {
+    inner: str,
+}
+
+/// Represents non-empty iterators over the characters of non-empty strings.
+pub type NonEmptyChars<'a> = NonEmptyAdapter<Chars<'a>>;
+
+impl<'a> TryFrom<&'a str> for &'a NonEmptyStr {
+    type Error = EmptyStr;
+
+    fn try_from(string: &'a str) -> Result<Self, Self::Error> {
+        NonEmptyStr::try_from_str(string)
+    }
+}
+
+impl<'a> TryFrom<&'a mut str> for &'a mut NonEmptyStr {
+    type Error = EmptyStr;
+
+    fn try_from(string: &'a mut str) -> Result<Self, Self::Error> {
+        NonEmptyStr::try_from_mut_str(string)
+    }
+}
+
+impl<'a> From<&'a NonEmptyStr> for &'a str {
+    fn from(string: &'a NonEmptyStr) -> Self {
+        string.as_str()
+    }
+}
+
+impl<'a> From<&'a mut NonEmptyStr> for &'a mut str {
+    fn from(string: &'a mut NonEmptyStr) -> Self {
+        string.as_mut_str()
+    }
+}
+
+impl<'a> TryFrom<&'a str> for &'a NonEmptyBytes {
+    type Error = EmptyStr;
+
+    fn try_from(string: &'a str) -> Result<Self, Self::Error> {
+        let non_empty: &NonEmptyStr = string.try_into()?;
+
+        Ok(non_empty.as_non_empty_bytes())
+    }
+}
+
+impl<'a> From<&'a NonEmptyStr> for &'a NonEmptyBytes {
+    fn from(string: &'a NonEmptyStr) -> Self {
+        string.as_non_empty_bytes()
+    }
+}
+
+impl AsRef<Self> for NonEmptyStr {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl AsRef<str> for NonEmptyStr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for NonEmptyStr {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl AsRef<NonEmptyBytes> for NonEmptyStr {
+    fn as_ref(&self) -> &NonEmptyBytes {
+        self.as_non_empty_bytes()
+    }
+}
+
+impl AsMut<Self> for NonEmptyStr {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl AsMut<str> for NonEmptyStr {
+    fn as_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl Deref for NonEmptyStr {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl DerefMut for NonEmptyStr {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_str()
+    }
+}
+
+impl fmt::Display for NonEmptyStr {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
+
+impl NonEmptyStr {
+    /// Constructs [`Self`] from anything that can be converted to string, provided it is non-empty.
+    ///
+    /// Prefer [`try_from_str`] if only [`str`] is used, as this allows for `const` evaluation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyStr`] if the string is empty.
+    ///
+    /// [`try_from_str`]: Self::try_from_str
+    pub fn try_new<S: AsRef<str> + ?Sized>(string: &S) -> Result<&Self, EmptyStr> {
+        Self::try_from_str(string.as_ref())
+    }
+
+    /// Similar to [`try_new`], but the error is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptyStr;
+    ///
+    /// let nekit = NonEmptyStr::new("nekit").unwrap();
+    ///
+    /// assert!(NonEmptyStr::new("").is_none());
+    /// ```
+    ///
+    /// [`try_new`]: Self::try_new
+    pub fn new<S: AsRef<str> + ?Sized>(string: &S) -> Option<&Self> {
+        Self::try_new(string).ok()
+    }
+
+    /// Constructs [`Self`] from [`str`], provided the string is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyStr`] if the string is empty.
+    pub const fn try_from_str(string: &str) -> Result<&Self, EmptyStr> {
+        if string.is_empty() {
+            return Err(EmptyStr);
+        }
+
+        // SAFETY: the string is non-empty at this point
+        Ok(unsafe { Self::from_str_unchecked(string) })
+    }
+
+    /// Constructs [`Self`] from mutable [`str`], provided the string is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyStr`] if the string is empty.
+    pub const fn try_from_mut_str(string: &mut str) -> Result<&mut Self, EmptyStr> {
+        if string.is_empty() {
+            return Err(EmptyStr);
+        }
+
+        // SAFETY: the string is non-empty at this point
+        Ok(unsafe { Self::from_mut_str_unchecked(string) })
+    }
+
+    /// Constructs [`Self`] from [`str`], without checking if the string is empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is non-empty.
+    #[must_use]
+    pub const unsafe fn from_str_unchecked(string: &str) -> &Self {
+        debug_assert!(!string.is_empty());
+
+        // SAFETY: the caller must ensure that the string is non-empty
+        // `Self` is `repr(transparent)`, so it is safe to transmute
+        unsafe { &*(ptr::from_ref(string) as *const Self) }
+    }
+
+    /// Constructs [`Self`] from mutable [`str`], without checking if the string is empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is non-empty.
+    #[must_use]
+    pub const unsafe fn from_mut_str_unchecked(string: &mut str) -> &mut Self {
+        debug_assert!(!string.is_empty());
+
+        // SAFETY: the caller must ensure that the string is non-empty
+        // `Self` is `repr(transparent)`, so it is safe to transmute
+        unsafe { &mut *(ptr::from_mut(string) as *mut Self) }
+    }
+
+    /// Converts [`NonEmptyBytes`] to [`Self`], provided the bytes are valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Utf8Error`] if the bytes are not valid UTF-8.
+    pub const fn from_utf8(bytes: &NonEmptyBytes) -> Result<&Self, Utf8Error> {
+        match from_utf8(bytes.as_slice()) {
+            // SAFETY: the bytes are non-empty, so is the string
+            Ok(string) => Ok(unsafe { Self::from_str_unchecked(string) }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Converts mutable [`NonEmptyBytes`] to [`Self`], provided the bytes are valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Utf8Error`] if the bytes are not valid UTF-8.
+    pub const fn from_utf8_mut(bytes: &mut NonEmptyBytes) -> Result<&mut Self, Utf8Error> {
+        match from_utf8_mut(bytes.as_mut_slice()) {
+            // SAFETY: the bytes are non-empty, so is the string
+            Ok(string) => Ok(unsafe { Self::from_mut_str_unchecked(string) }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Converts [`NonEmptyBytes`] to [`Self`], without checking that the bytes are valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the bytes are valid UTF-8.
+    #[must_use]
+    pub const unsafe fn from_utf8_unchecked(bytes: &NonEmptyBytes) -> &Self {
+        // SAFETY: the caller must ensure that the bytes are valid UTF-8
+        let string = unsafe { from_utf8_unchecked(bytes.as_slice()) };
+
+        // SAFETY: the bytes are non-empty, so is the string
+        unsafe { Self::from_str_unchecked(string) }
+    }
+
+    /// Returns the contained string.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        &self.inner
+    }
+
+    /// Returns the contained mutable string.
+    #[must_use]
+    pub const fn as_mut_str(&mut self) -> &mut str {
+        &mut self.inner
+    }
+
+    /// Checks if the string is empty. Always returns [`false`].
+    ///
+    /// This method is marked as deprecated since the string is never empty.
+    #[must_use]
+    #[deprecated = "this string is never empty"]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the length of the string in bytes as [`Size`].
+    #[must_use]
+    pub const fn len(&self) -> Size {
+        let len = self.as_str().len();
+
+        // SAFETY: the string is non-empty by construction,
+        // therefore its length is guaranteed to be non-zero
+        unsafe { Size::new_unchecked(len) }
+    }
+
+    /// Returns the bytes of the string.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+
+    /// Returns the bytes of the string as [`NonEmptyBytes`].
+    #[must_use]
+    pub const fn as_non_empty_bytes(&self) -> &NonEmptyBytes {
+        // SAFETY: the string is non-empty by construction, so are its bytes
+        unsafe { NonEmptyBytes::from_slice_unchecked(self.as_bytes()) }
+    }
+
+    /// Returns the bytes of the string as mutable [`NonEmptyBytes`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the bytes remain valid UTF-8.
+    #[must_use]
+    pub const unsafe fn as_non_empty_bytes_mut(&mut self) -> &mut NonEmptyBytes {
+        // SAFETY: the caller must ensure that the bytes remain valid UTF-8
+        let bytes = unsafe { self.as_mut_str().as_bytes_mut() };
+
+        // SAFETY: the string is non-empty by construction, so are its bytes
+        unsafe { NonEmptyBytes::from_mut_slice_unchecked(bytes) }
+    }
+
+    /// Returns regular iterator over the characters of the string.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn chars(&self) -> Chars<'_> {
+        self.as_str().chars()
+    }
+
+    /// Returns non-empty iterator over the characters of the string.
+    pub fn non_empty_chars(&self) -> NonEmptyChars<'_> {
+        // SAFETY: the string is non-empty by construction, so it has at least one character
+        unsafe { NonEmptyAdapter::new(self.chars()) }
+    }
+
+    /// Returns the first character of the string.
+    #[must_use]
+    pub fn first(&self) -> char {
+        let option = self.chars().next();
+
+        // SAFETY: the string is non-empty by construction, so there is always some first character
+        unsafe { option.unwrap_unchecked() }
+    }
+
+    /// Returns the last character of the string.
+    #[must_use]
+    pub fn last(&self) -> char {
+        let option = self.chars().next_back();
+
+        // SAFETY: the string is non-empty by construction, so there is always some last character
+        unsafe { option.unwrap_unchecked() }
+    }
+
+    // NOTE: other methods are available via deref coercion to `str`
+}
+
+impl NonEmptyBytes {
+    /// Converts the bytes to [`str`], provided they are valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Utf8Error`] if the bytes are not valid UTF-8.
+    pub const fn as_str(&self) -> Result<&str, Utf8Error> {
+        from_utf8(self.as_slice())
+    }
+
+    /// Converts the bytes to [`NonEmptyStr`], provided they are valid UTF-8.
+    ///
+    /// This is equivalent to [`NonEmptyStr::from_utf8`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Utf8Error`] if the bytes are not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    ///
+    /// let bytes = NonEmptyBytes::from_slice(b"nekit").unwrap();
+    ///
+    /// assert_eq!(bytes.as_non_empty_str().unwrap().as_str(), "nekit");
+    ///
+    /// let invalid = NonEmptyBytes::from_slice(b"\xFF").unwrap();
+    ///
+    /// assert!(invalid.as_str().is_err());
+    /// ```
+    pub const fn as_non_empty_str(&self) -> Result<&NonEmptyStr, Utf8Error> {
+        NonEmptyStr::from_utf8(self)
+    }
+}
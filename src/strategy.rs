@@ -0,0 +1,99 @@
+//! Non-empty [`proptest`] strategies.
+//!
+//! Generated values are guaranteed to be non-empty, and shrinking never shrinks them to empty.
+
+#[cfg(not(feature = "proptest"))]
+compile_error!("expected `proptest` to be enabled");
+
+use proptest::{
+    arbitrary::{Arbitrary, any_with},
+    collection::{SizeRange, VecStrategy, vec},
+    strategy::{Map, Strategy},
+};
+
+use crate::{
+    boxed::NonEmptyBoxedSlice,
+    slice::NonEmptySlice,
+    vec::{EMPTY_VEC, NonEmptyVec},
+};
+
+/// Represents strategies generating [`NonEmptyVec<T>`] values.
+pub type NonEmptyVecStrategy<S> =
+    Map<VecStrategy<S>, fn(Vec<<S as Strategy>::Value>) -> NonEmptyVec<<S as Strategy>::Value>>;
+
+/// Represents strategies generating [`NonEmptyBoxedSlice<T>`] values.
+pub type NonEmptyBoxedSliceStrategy<S> = Map<
+    NonEmptyVecStrategy<S>,
+    fn(NonEmptyVec<<S as Strategy>::Value>) -> NonEmptyBoxedSlice<<S as Strategy>::Value>,
+>;
+
+// NOTE: raising the upper bound is required in order to keep the range non-empty,
+// as otherwise no values could be generated at all
+
+fn non_empty_size(size: SizeRange) -> SizeRange {
+    let (start, end) = size.start_end_incl();
+
+    let start = start.max(1);
+
+    (start..=end.max(start)).into()
+}
+
+fn from_vec<T>(vec: Vec<T>) -> NonEmptyVec<T> {
+    NonEmptyVec::new(vec).expect(EMPTY_VEC)
+}
+
+/// Constructs strategies generating [`NonEmptyVec<T>`] values with items drawn from `element`.
+///
+/// The lower bound of the `size` range is raised to one if needed; in case the upper bound
+/// is less than one (meaning the range only allows empty values), it is raised to one as well,
+/// so that, for instance, `0..1` becomes `1..=1`.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_slice::strategy::non_empty_vec;
+/// use proptest::{prelude::any, proptest};
+///
+/// proptest!(|(non_empty in non_empty_vec(any::<u8>(), 0..8))| {
+///     assert!(non_empty.len().get() < 8);
+/// });
+/// ```
+pub fn non_empty_vec<S: Strategy>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> NonEmptyVecStrategy<S> {
+    vec(element, non_empty_size(size.into())).prop_map(from_vec)
+}
+
+/// Constructs strategies generating [`NonEmptyBoxedSlice<T>`] values
+/// with items drawn from `element`.
+///
+/// The lower bound of the `size` range is raised to one if needed; in case the upper bound
+/// is less than one (meaning the range only allows empty values), it is raised to one as well,
+/// so that, for instance, `0..1` becomes `1..=1`.
+pub fn non_empty_boxed_slice<S: Strategy>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> NonEmptyBoxedSliceStrategy<S> {
+    non_empty_vec(element, size).prop_map(NonEmptySlice::from_non_empty_vec)
+}
+
+impl<T: Arbitrary> Arbitrary for NonEmptyVec<T> {
+    type Parameters = (SizeRange, T::Parameters);
+
+    type Strategy = NonEmptyVecStrategy<T::Strategy>;
+
+    fn arbitrary_with((size, parameters): Self::Parameters) -> Self::Strategy {
+        non_empty_vec(any_with::<T>(parameters), size)
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for NonEmptyBoxedSlice<T> {
+    type Parameters = (SizeRange, T::Parameters);
+
+    type Strategy = NonEmptyBoxedSliceStrategy<T::Strategy>;
+
+    fn arbitrary_with((size, parameters): Self::Parameters) -> Self::Strategy {
+        non_empty_boxed_slice(any_with::<T>(parameters), size)
+    }
+}
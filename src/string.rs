@@ -0,0 +1,441 @@
+//! Non-empty [`String`].
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("expected either `std` or `alloc` to be enabled");
+
+#[cfg(feature = "std")]
+use std::{borrow::Cow, string::FromUtf8Error};
+
+#[cfg(all(not(feature = "std"), feature = "alloc"))]
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::{FromUtf8Error, String},
+};
+
+use core::{
+    borrow::{Borrow, BorrowMut},
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use non_zero_size::Size;
+use thiserror::Error;
+
+use crate::{
+    format,
+    slice::NonEmptyBytes,
+    str::{EmptyStr, NonEmptyStr},
+    vec::NonEmptyByteVec,
+};
+
+/// The error message used when the string is empty.
+pub const EMPTY_STRING: &str = "the string is empty";
+
+/// Similar to [`EmptyStr`], but holds the empty string provided.
+#[derive(Error)]
+#[error("{EMPTY_STRING}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_slice::string),
+        help("make sure the string is non-empty")
+    )
+)]
+pub struct EmptyString {
+    string: String,
+}
+
+format::debug!(str EmptyString, string);
+
+impl EmptyString {
+    // NOTE: this is private to prevent creating this error with non-empty strings
+    pub(crate) const fn new(string: String) -> Self {
+        Self { string }
+    }
+
+    /// Returns the contained empty string.
+    #[must_use]
+    pub fn get(self) -> String {
+        self.string
+    }
+}
+
+/// Represents non-empty [`String`] values.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonEmptyString {
+    inner: String,
+}
+
+impl ToOwned for NonEmptyStr {
+    type Owned = NonEmptyString;
+
+    fn to_owned(&self) -> Self::Owned {
+        self.to_non_empty_string()
+    }
+}
+
+impl Borrow<NonEmptyStr> for NonEmptyString {
+    fn borrow(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+impl BorrowMut<NonEmptyStr> for NonEmptyString {
+    fn borrow_mut(&mut self) -> &mut NonEmptyStr {
+        self.as_non_empty_mut_str()
+    }
+}
+
+impl Borrow<str> for NonEmptyString {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl TryFrom<String> for NonEmptyString {
+    type Error = EmptyString;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        Self::new(string)
+    }
+}
+
+impl TryFrom<&str> for NonEmptyString {
+    type Error = EmptyStr;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        let non_empty_str: &NonEmptyStr = string.try_into()?;
+
+        Ok(non_empty_str.into())
+    }
+}
+
+impl From<NonEmptyString> for String {
+    fn from(non_empty: NonEmptyString) -> Self {
+        non_empty.into_string()
+    }
+}
+
+impl From<&NonEmptyStr> for NonEmptyString {
+    fn from(non_empty: &NonEmptyStr) -> Self {
+        non_empty.to_non_empty_string()
+    }
+}
+
+impl From<NonEmptyString> for NonEmptyByteVec {
+    fn from(non_empty: NonEmptyString) -> Self {
+        non_empty.into_non_empty_bytes()
+    }
+}
+
+impl TryFrom<String> for NonEmptyByteVec {
+    type Error = EmptyString;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        NonEmptyString::new(string).map(NonEmptyString::into_non_empty_bytes)
+    }
+}
+
+impl AsRef<Self> for NonEmptyString {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl AsRef<NonEmptyStr> for NonEmptyString {
+    fn as_ref(&self) -> &NonEmptyStr {
+        self.as_non_empty_str()
+    }
+}
+
+impl AsMut<NonEmptyStr> for NonEmptyString {
+    fn as_mut(&mut self) -> &mut NonEmptyStr {
+        self.as_non_empty_mut_str()
+    }
+}
+
+impl AsRef<str> for NonEmptyString {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for NonEmptyString {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Deref for NonEmptyString {
+    type Target = NonEmptyStr;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_non_empty_str()
+    }
+}
+
+impl DerefMut for NonEmptyString {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_non_empty_mut_str()
+    }
+}
+
+impl fmt::Display for NonEmptyString {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(formatter)
+    }
+}
+
+impl NonEmptyString {
+    /// Constructs [`Self`], provided that the [`String`] provided is non-empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyString`] if the provided string is empty.
+    pub const fn new(string: String) -> Result<Self, EmptyString> {
+        if string.is_empty() {
+            return Err(EmptyString::new(string));
+        }
+
+        // SAFETY: the string is non-empty at this point
+        Ok(unsafe { Self::new_unchecked(string) })
+    }
+
+    /// Constructs [`Self`] without checking that the [`String`] is non-empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the string is non-empty.
+    #[must_use]
+    pub const unsafe fn new_unchecked(inner: String) -> Self {
+        Self { inner }
+    }
+
+    /// Constructs [`Self`] containing the single character provided.
+    pub fn single(character: char) -> Self {
+        let mut string = String::new();
+
+        string.push(character);
+
+        // SAFETY: non-empty construction
+        unsafe { Self::new_unchecked(string) }
+    }
+
+    /// Converts [`NonEmptyByteVec`] to [`Self`], provided the bytes are valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromUtf8Error`] if the bytes are not valid UTF-8.
+    pub fn from_utf8(bytes: NonEmptyByteVec) -> Result<Self, FromUtf8Error> {
+        let string = String::from_utf8(bytes.into_vec())?;
+
+        // SAFETY: the bytes are non-empty, so is the string
+        Ok(unsafe { Self::new_unchecked(string) })
+    }
+
+    /// Converts [`NonEmptyByteVec`] to [`Self`], without checking that the bytes are valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the bytes are valid UTF-8.
+    #[must_use]
+    pub unsafe fn from_utf8_unchecked(bytes: NonEmptyByteVec) -> Self {
+        // SAFETY: the caller must ensure that the bytes are valid UTF-8
+        let string = unsafe { String::from_utf8_unchecked(bytes.into_vec()) };
+
+        // SAFETY: the bytes are non-empty, so is the string
+        unsafe { Self::new_unchecked(string) }
+    }
+
+    /// Returns the contained string reference as [`NonEmptyStr`].
+    #[must_use]
+    pub const fn as_non_empty_str(&self) -> &NonEmptyStr {
+        // SAFETY: the string is non-empty by construction
+        unsafe { NonEmptyStr::from_str_unchecked(self.as_str()) }
+    }
+
+    /// Returns the contained string reference as mutable [`NonEmptyStr`].
+    #[must_use]
+    pub const fn as_non_empty_mut_str(&mut self) -> &mut NonEmptyStr {
+        // SAFETY: the string is non-empty by construction
+        unsafe { NonEmptyStr::from_mut_str_unchecked(self.as_mut_str()) }
+    }
+
+    /// Extracts the string slice containing the entire string.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        self.as_string().as_str()
+    }
+
+    /// Extracts the mutable string slice containing the entire string.
+    #[must_use]
+    pub const fn as_mut_str(&mut self) -> &mut str {
+        // SAFETY: getting mutable string slice can not make the string empty
+        unsafe { self.as_mut_string().as_mut_str() }
+    }
+
+    /// Returns the contained [`String`] behind immutable reference.
+    #[must_use]
+    pub const fn as_string(&self) -> &String {
+        &self.inner
+    }
+
+    /// Returns the contained [`String`] behind mutable reference.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the returned string remains non-empty.
+    #[must_use]
+    pub const unsafe fn as_mut_string(&mut self) -> &mut String {
+        &mut self.inner
+    }
+
+    /// Returns the contained [`String`].
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.inner
+    }
+
+    /// Converts [`Self`] into [`NonEmptyByteVec`].
+    #[must_use]
+    pub fn into_non_empty_bytes(self) -> NonEmptyByteVec {
+        // SAFETY: the string is non-empty by construction, so are its bytes
+        unsafe { NonEmptyByteVec::new_unchecked(self.into_string().into_bytes()) }
+    }
+
+    /// Checks if the string is empty. Always returns [`false`].
+    ///
+    /// This method is marked as deprecated since the string is never empty.
+    #[must_use]
+    #[deprecated = "this string is never empty"]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the length of the string in bytes as [`Size`].
+    #[must_use]
+    pub const fn len(&self) -> Size {
+        self.as_non_empty_str().len()
+    }
+
+    /// Returns the capacity of the string in bytes as [`Size`].
+    #[must_use]
+    pub const fn capacity(&self) -> Size {
+        let capacity = self.as_string().capacity();
+
+        // SAFETY: non-empty string implies non-zero capacity
+        unsafe { Size::new_unchecked(capacity) }
+    }
+
+    /// Appends the given character to the end of the string.
+    pub fn push(&mut self, character: char) {
+        // SAFETY: pushing can not make the string empty
+        unsafe {
+            self.as_mut_string().push(character);
+        }
+    }
+
+    /// Appends the given string slice to the end of the string.
+    pub fn push_str(&mut self, string: &str) {
+        // SAFETY: pushing can not make the string empty
+        unsafe {
+            self.as_mut_string().push_str(string);
+        }
+    }
+
+    /// Checks whether the string is almost empty, meaning it only contains one character.
+    #[must_use]
+    pub fn next_empty(&self) -> bool {
+        let mut chars = self.chars();
+
+        chars.next_back();
+
+        chars.as_str().is_empty()
+    }
+
+    /// The negated version of [`next_empty`].
+    ///
+    /// [`next_empty`]: Self::next_empty
+    #[must_use]
+    pub fn next_non_empty(&self) -> bool {
+        !self.next_empty()
+    }
+
+    /// Removes the last character from the string and returns it,
+    /// or [`None`] if the string would become empty.
+    pub fn pop(&mut self) -> Option<char> {
+        self.next_non_empty()
+            // SAFETY: popping only if the string would remain non-empty
+            .then(|| unsafe { self.as_mut_string().pop() })
+            .flatten()
+    }
+}
+
+impl NonEmptyStr {
+    /// Constructs [`NonEmptyString`] from the non-empty string via copying.
+    pub fn to_non_empty_string(&self) -> NonEmptyString {
+        // SAFETY: the string is non-empty by construction
+        unsafe { NonEmptyString::new_unchecked(self.as_str().to_owned()) }
+    }
+}
+
+impl NonEmptyBytes {
+    /// Converts the bytes to string, replacing invalid UTF-8 sequences with
+    /// [`REPLACEMENT_CHARACTER`].
+    ///
+    /// See [`String::from_utf8_lossy`] for more information.
+    ///
+    /// [`REPLACEMENT_CHARACTER`]: char::REPLACEMENT_CHARACTER
+    #[must_use]
+    pub fn to_utf8_lossy(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(self.as_slice())
+    }
+
+    /// Similar to [`to_utf8_lossy`], but returns non-empty strings.
+    ///
+    /// Since invalid UTF-8 sequences are replaced rather than removed,
+    /// the resulting string is always non-empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    ///
+    /// let bytes = NonEmptyBytes::from_slice(b"nekit\xFF").unwrap();
+    ///
+    /// assert_eq!(bytes.to_non_empty_utf8_lossy().as_str(), "nekit\u{FFFD}");
+    /// ```
+    ///
+    /// [`to_utf8_lossy`]: Self::to_utf8_lossy
+    #[must_use]
+    pub fn to_non_empty_utf8_lossy(&self) -> Cow<'_, NonEmptyStr> {
+        match self.to_utf8_lossy() {
+            // SAFETY: the bytes are non-empty, so is the string
+            Cow::Borrowed(string) => {
+                Cow::Borrowed(unsafe { NonEmptyStr::from_str_unchecked(string) })
+            }
+            // SAFETY: the bytes are non-empty, so is the string
+            Cow::Owned(string) => Cow::Owned(unsafe { NonEmptyString::new_unchecked(string) }),
+        }
+    }
+}
+
+impl Extend<char> for NonEmptyString {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iterable: I) {
+        // SAFETY: extending can not make the string empty
+        unsafe {
+            self.as_mut_string().extend(iterable);
+        }
+    }
+}
+
+impl<'a> Extend<&'a str> for NonEmptyString {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iterable: I) {
+        // SAFETY: extending can not make the string empty
+        unsafe {
+            self.as_mut_string().extend(iterable);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+#[cfg(not(feature = "subtle"))]
+compile_error!("expected `subtle` to be enabled");
+
+use subtle::{Choice, ConstantTimeEq};
+
+use crate::slice::NonEmptySlice;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::vec::NonEmptyVec;
+
+// NOTE: similar to `[T]`, comparing non-empty slices of different lengths short-circuits,
+// so only the lengths are leaked, but never the contents
+
+impl<T: ConstantTimeEq> ConstantTimeEq for NonEmptySlice<T> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_slice().ct_eq(other.as_slice())
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: ConstantTimeEq> ConstantTimeEq for NonEmptyVec<T> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_slice().ct_eq(other.as_slice())
+    }
+}
@@ -4,19 +4,30 @@
 compile_error!("expected either `std` or `alloc` to be enabled");
 
 #[cfg(feature = "std")]
-use std::{collections::TryReserveError, vec::IntoIter};
+use std::{
+    collections::{HashMap, TryReserveError, hash_map::Entry},
+    vec::IntoIter,
+};
 
 #[cfg(all(not(feature = "std"), feature = "alloc"))]
 use alloc::{
     borrow::ToOwned,
     collections::TryReserveError,
+    string::String,
     vec::{IntoIter, Vec},
 };
 
 use core::{
+    alloc::Layout,
     borrow::{Borrow, BorrowMut},
-    mem::MaybeUninit,
-    ops::{Deref, DerefMut, Index, IndexMut, RangeBounds},
+    cmp::Ordering,
+    fmt,
+    hash::Hash,
+    iter::once,
+    marker::PhantomData,
+    mem::{ManuallyDrop, MaybeUninit, replace, size_of},
+    ops::{Bound, Deref, DerefMut, Index, IndexMut, RangeBounds, RangeInclusive},
+    ptr::{self, NonNull},
     slice::{Iter, IterMut, SliceIndex, from_raw_parts_mut},
 };
 
@@ -30,7 +41,7 @@ use crate::{
     boxed::EmptyBoxedSlice,
     format,
     iter::{IntoNonEmptyIter, NonEmptyIter, NonEmptyIterMut},
-    slice::{EmptySlice, NonEmptySlice},
+    slice::{EmptySlice, NonEmptyBytes, NonEmptySlice},
 };
 
 /// The error message used when the vector is empty.
@@ -40,7 +51,7 @@ pub const EMPTY_VEC: &str = "the vector is empty";
 ///
 /// [`EmptySlice`]: crate::slice::EmptySlice
 #[derive(Error)]
-#[error("{EMPTY_VEC}")]
+#[error("{}", format::Contextual::new(*.context, EMPTY_VEC))]
 #[cfg_attr(
     feature = "diagnostics",
     derive(miette::Diagnostic),
@@ -48,6 +59,7 @@ pub const EMPTY_VEC: &str = "the vector is empty";
 )]
 pub struct EmptyVec<T> {
     vec: Vec<T>,
+    context: Option<&'static str>,
 }
 
 format::debug!(EmptyVec, vec);
@@ -55,7 +67,33 @@ format::debug!(EmptyVec, vec);
 impl<T> EmptyVec<T> {
     // NOTE: this is private to prevent creating this error with non-empty vectors
     pub(crate) const fn new(vec: Vec<T>) -> Self {
-        Self { vec }
+        Self { vec, context: None }
+    }
+
+    /// Attaches the given context to [`Self`], which is then included in the error message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptyVec;
+    ///
+    /// let error = NonEmptyVec::<u8>::new(Vec::new())
+    ///     .unwrap_err()
+    ///     .with_context("expected at least one port");
+    ///
+    /// assert_eq!(error.to_string(), "expected at least one port: the vector is empty");
+    /// ```
+    #[must_use]
+    pub const fn with_context(mut self, context: &'static str) -> Self {
+        self.context = Some(context);
+
+        self
+    }
+
+    /// Returns the context attached to [`Self`], if any.
+    #[must_use]
+    pub const fn context(&self) -> Option<&'static str> {
+        self.context
     }
 
     /// Returns the contained empty vector.
@@ -64,10 +102,15 @@ impl<T> EmptyVec<T> {
         self.vec
     }
 
-    /// Constructs [`Self`] from [`EmptyBoxedSlice<T>`].
+    /// Constructs [`Self`] from [`EmptyBoxedSlice<T>`], preserving its context.
     #[must_use]
     pub fn from_empty_boxed_slice(empty: EmptyBoxedSlice<T>) -> Self {
-        Self::new(empty.get().into_vec())
+        let context = empty.context();
+
+        Self {
+            vec: empty.get().into_vec(),
+            context,
+        }
     }
 
     /// Converts [`Self`] into [`EmptyBoxedSlice<T>`].
@@ -80,7 +123,89 @@ impl<T> EmptyVec<T> {
 /// Represents empty byte vectors, [`EmptyVec<u8>`].
 pub type EmptyByteVec = EmptyVec<u8>;
 
+/// The error message used when the length of the vector is out of range.
+pub const LENGTH_OUT_OF_RANGE: &str = "the length of the vector is out of range";
+
+/// Represents errors returned when the length of the vector is not within the expected range.
+///
+/// Holds the vector provided, along with the expected range.
+#[derive(Error)]
+#[error(
+    "{LENGTH_OUT_OF_RANGE} (expected {}..={}, got {})",
+    .range.start(),
+    .range.end(),
+    .vec.len()
+)]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(miette::Diagnostic),
+    diagnostic(
+        code(non_empty_slice::vec::length),
+        help("make sure the length of the vector is within the range")
+    )
+)]
+pub struct LengthError<T> {
+    vec: Vec<T>,
+    range: RangeInclusive<Size>,
+}
+
+impl<T> fmt::Debug for LengthError<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct(stringify!(LengthError))
+            .field(stringify!(len), &self.vec.len())
+            .field(stringify!(range), &self.range)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> LengthError<T> {
+    // NOTE: this is private to prevent creating this error with vectors of valid lengths
+    pub(crate) const fn new(vec: Vec<T>, range: RangeInclusive<Size>) -> Self {
+        Self { vec, range }
+    }
+
+    /// Returns the actual length of the vector.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Checks whether the vector is empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Returns the expected range of lengths.
+    #[must_use]
+    pub const fn range(&self) -> &RangeInclusive<Size> {
+        &self.range
+    }
+
+    /// Returns the contained vector.
+    #[must_use]
+    pub fn get(self) -> Vec<T> {
+        self.vec
+    }
+}
+
 /// Represents non-empty [`Vec<T>`] values.
+///
+/// Non-empty vectors can be compared with vectors, slices and arrays directly:
+///
+/// ```
+/// use non_empty_slice::{NonEmptyVec, non_empty_slice, non_empty_vec};
+///
+/// let non_empty: NonEmptyVec<i32> = non_empty_vec![1, 2, 3];
+///
+/// assert_eq!(non_empty, vec![1, 2, 3]);
+/// assert_eq!(non_empty, [1, 2, 3]);
+/// assert_eq!(non_empty, non_empty_slice!(&[1, 2, 3]));
+/// assert_eq!(vec![1, 2, 3], non_empty);
+///
+/// assert!(non_empty < vec![1, 2, 4]);
+/// ```
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct NonEmptyVec<T> {
@@ -101,6 +226,30 @@ impl<T: Clone> Clone for NonEmptyVec<T> {
     }
 }
 
+// NOTE: since the vector can not be empty, the default one contains the single default value
+
+/// Constructs [`NonEmptyVec<T>`] containing the single default value.
+///
+/// # Examples
+///
+/// ```
+/// use non_empty_slice::NonEmptyVec;
+///
+/// #[derive(Default)]
+/// struct Config {
+///     ports: NonEmptyVec<u16>,
+/// }
+///
+/// let config = Config::default();
+///
+/// assert_eq!(config.ports, [0]);
+/// ```
+impl<T: Default> Default for NonEmptyVec<T> {
+    fn default() -> Self {
+        Self::single(T::default())
+    }
+}
+
 /// Represents non-empty byte vectors, [`NonEmptyVec<u8>`].
 pub type NonEmptyByteVec = NonEmptyVec<u8>;
 
@@ -124,6 +273,25 @@ impl<T> BorrowMut<NonEmptySlice<T>> for NonEmptyVec<T> {
     }
 }
 
+/// Hashing non-empty vectors is consistent with hashing slices,
+/// meaning that non-empty vectors can be looked up in hash maps via slices.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use non_empty_slice::{NonEmptyByteVec, NonEmptyBytes};
+///
+/// let nekit = NonEmptyBytes::from_slice(b"nekit").unwrap();
+///
+/// let mut map = HashMap::new();
+///
+/// map.insert(NonEmptyByteVec::from(nekit), 42);
+///
+/// assert_eq!(map.get(b"nekit".as_slice()), Some(&42));
+/// assert_eq!(map.get(nekit), Some(&42));
+/// ```
 impl<T> Borrow<[T]> for NonEmptyVec<T> {
     fn borrow(&self) -> &[T] {
         self.as_slice()
@@ -150,6 +318,48 @@ impl<T> From<NonEmptyVec<T>> for Vec<T> {
     }
 }
 
+impl<'a, T> TryFrom<&'a Vec<T>> for &'a NonEmptySlice<T> {
+    type Error = EmptySlice;
+
+    fn try_from(vec: &'a Vec<T>) -> Result<Self, Self::Error> {
+        NonEmptySlice::try_from_slice(vec)
+    }
+}
+
+impl<'a, T> TryFrom<&'a mut Vec<T>> for &'a mut NonEmptySlice<T> {
+    type Error = EmptySlice;
+
+    fn try_from(vec: &'a mut Vec<T>) -> Result<Self, Self::Error> {
+        NonEmptySlice::try_from_mut_slice(vec)
+    }
+}
+
+impl<'a, T> From<&'a NonEmptyVec<T>> for &'a NonEmptySlice<T> {
+    fn from(non_empty: &'a NonEmptyVec<T>) -> Self {
+        non_empty.as_non_empty_slice()
+    }
+}
+
+impl<'a, T> From<&'a mut NonEmptyVec<T>> for &'a mut NonEmptySlice<T> {
+    fn from(non_empty: &'a mut NonEmptyVec<T>) -> Self {
+        non_empty.as_non_empty_mut_slice()
+    }
+}
+
+// NOTE: empty arrays are rejected at compile time, see `from_array`
+
+impl<T, const N: usize> From<[T; N]> for NonEmptyVec<T> {
+    fn from(array: [T; N]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+impl<T: Clone, const N: usize> From<&[T; N]> for NonEmptyVec<T> {
+    fn from(array: &[T; N]) -> Self {
+        NonEmptySlice::from_array(array).to_non_empty_vec()
+    }
+}
+
 impl<T: Clone> From<&NonEmptySlice<T>> for NonEmptyVec<T> {
     fn from(non_empty: &NonEmptySlice<T>) -> Self {
         non_empty.to_non_empty_vec()
@@ -253,6 +463,78 @@ impl<T, I: SliceIndex<[T]>> IndexMut<I> for NonEmptyVec<T> {
     }
 }
 
+impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for NonEmptyVec<T> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<NonEmptyVec<U>> for Vec<T> {
+    fn eq(&self, other: &NonEmptyVec<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<NonEmptySlice<U>> for NonEmptyVec<T> {
+    fn eq(&self, other: &NonEmptySlice<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<NonEmptyVec<U>> for NonEmptySlice<T> {
+    fn eq(&self, other: &NonEmptyVec<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<&NonEmptySlice<U>> for NonEmptyVec<T> {
+    fn eq(&self, other: &&NonEmptySlice<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<NonEmptyVec<U>> for &NonEmptySlice<T> {
+    fn eq(&self, other: &NonEmptyVec<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<[U]> for NonEmptyVec<T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<NonEmptyVec<U>> for [T] {
+    fn eq(&self, other: &NonEmptyVec<U>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for NonEmptyVec<T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<NonEmptyVec<U>> for [T; N] {
+    fn eq(&self, other: &NonEmptyVec<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialOrd> PartialOrd<Vec<T>> for NonEmptyVec<T> {
+    fn partial_cmp(&self, other: &Vec<T>) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: PartialOrd> PartialOrd<NonEmptyVec<T>> for Vec<T> {
+    fn partial_cmp(&self, other: &NonEmptyVec<T>) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
 impl<T> NonEmptyVec<T> {
     /// Constructs [`Self`], provided that the [`Vec<T>`] provided is non-empty.
     ///
@@ -288,6 +570,101 @@ impl<T> NonEmptyVec<T> {
         Ok(unsafe { Self::new_unchecked(vector) })
     }
 
+    /// Constructs [`Self`], provided the length of the vector is within the given range.
+    ///
+    /// Since the range starts at some non-zero [`Size`], this also checks for non-emptiness.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LengthError<T>`] containing the vector if its length is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::{NonEmptyVec, const_size};
+    ///
+    /// let range = const_size!(1)..=const_size!(3);
+    ///
+    /// let non_empty = NonEmptyVec::new_with_len_in(vec![1, 2], range.clone()).unwrap();
+    ///
+    /// assert_eq!(non_empty, [1, 2]);
+    ///
+    /// let error = NonEmptyVec::new_with_len_in(vec![1, 2, 3, 4], range.clone()).unwrap_err();
+    ///
+    /// assert_eq!(error.len(), 4);
+    ///
+    /// assert!(NonEmptyVec::<i32>::new_with_len_in(Vec::new(), range).is_err());
+    /// ```
+    pub fn new_with_len_in(
+        vector: Vec<T>,
+        range: RangeInclusive<Size>,
+    ) -> Result<Self, LengthError<T>> {
+        let len = vector.len();
+
+        if !(range.start().get()..=range.end().get()).contains(&len) {
+            return Err(LengthError::new(vector, range));
+        }
+
+        // SAFETY: the length is at least the start of the range, which is non-zero
+        Ok(unsafe { Self::new_unchecked(vector) })
+    }
+
+    /// Constructs [`Self`] by collecting the items of the given iterable,
+    /// provided there is at least one item.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyVec<T>`] if the iterable does not yield any items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptyVec;
+    ///
+    /// let even = NonEmptyVec::from_iter_checked((1..=5).filter(|number| number % 2 == 0)).unwrap();
+    ///
+    /// assert_eq!(even.as_slice(), [2, 4]);
+    ///
+    /// assert!(NonEmptyVec::from_iter_checked((1..=5).filter(|number| *number > 5)).is_err());
+    /// ```
+    pub fn from_iter_checked<I: IntoIterator<Item = T>>(iterable: I) -> Result<Self, EmptyVec<T>> {
+        Self::new(iterable.into_iter().collect())
+    }
+
+    /// Constructs [`Self`] from [`[T; N]`](prim@array), checking that `N` is non-zero
+    /// at compile time.
+    ///
+    /// # Examples
+    ///
+    /// Basic snippet:
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptyVec;
+    ///
+    /// let non_empty = NonEmptyVec::from_array([1, 2, 3]);
+    ///
+    /// assert_eq!(non_empty, [1, 2, 3]);
+    ///
+    /// let converted: NonEmptyVec<_> = [1, 2, 3].into();
+    ///
+    /// assert_eq!(converted, non_empty);
+    /// ```
+    ///
+    /// Empty arrays result in compile-time errors:
+    ///
+    /// ```compile_fail
+    /// use non_empty_slice::NonEmptyVec;
+    ///
+    /// let never = NonEmptyVec::<i32>::from_array([]);
+    /// ```
+    #[must_use]
+    pub fn from_array<const N: usize>(array: [T; N]) -> Self {
+        const { assert!(N > 0, "{}", EMPTY_VEC) };
+
+        // SAFETY: `N` is non-zero, so the vector is non-empty
+        unsafe { Self::new_unchecked(array.into()) }
+    }
+
     /// Constructs [`Self`] without checking that the [`Vec<T>`] is non-empty.
     ///
     /// # Safety
@@ -298,6 +675,22 @@ impl<T> NonEmptyVec<T> {
         Self { inner }
     }
 
+    /// Constructs [`Self`] directly from the pointer, the non-zero length and the capacity.
+    ///
+    /// This is the non-empty counterpart of [`Vec::from_raw_parts`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must uphold all safety requirements of [`Vec::from_raw_parts`].
+    #[must_use]
+    pub unsafe fn from_raw_parts(ptr: NonNull<T>, len: Size, capacity: Size) -> Self {
+        // SAFETY: the caller must uphold all safety requirements of `Vec::from_raw_parts`
+        let vec = unsafe { Vec::from_raw_parts(ptr.as_ptr(), len.get(), capacity.get()) };
+
+        // SAFETY: the length is non-zero, so is the vector
+        unsafe { Self::new_unchecked(vec) }
+    }
+
     #[cfg(feature = "unsafe-assert")]
     const fn assert_non_empty(&self) {
         use core::hint::assert_unchecked;
@@ -378,287 +771,1460 @@ impl<T> NonEmptyVec<T> {
 
         self.into_vec_no_assert()
     }
-}
 
-impl<T: Clone> NonEmptyVec<T> {
-    /// Constructs [`Self`] from [`NonEmptySlice<T>`] via cloning.
+    /// Constructs [`NonEmptyVec<U>`] by applying the given function to each item
+    /// of the vector, consuming it.
     ///
-    /// # Examples
+    /// Unlike [`map`], this method takes the items by value.
     ///
-    /// Basic snippet:
+    /// The allocation is reused whenever `T` and `U` have the same (non-zero) size
+    /// and alignment, mapping the items in place.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// use non_empty_slice::{NonEmptyByteVec, NonEmptyBytes};
+    /// use non_empty_slice::non_empty_vec;
     ///
-    /// let nekit = NonEmptyBytes::from_slice(b"nekit").unwrap();
+    /// let non_empty = non_empty_vec![1_u32, 2, 3];
     ///
-    /// let owned = NonEmptyByteVec::from_non_empty_slice(nekit);
-    /// ```
-    pub fn from_non_empty_slice(non_empty: &NonEmptySlice<T>) -> Self {
-        // SAFETY: the slice is non-empty by construction
-        unsafe { Self::new_unchecked(non_empty.to_vec()) }
-    }
-}
-
-impl<T: Copy> NonEmptySlice<T> {
-    /// Creates [`NonEmptyVec<T>`] by repeating this non-empty slice certain number of times.
+    /// let pointer = non_empty.as_ptr().addr();
     ///
-    /// # Panics
+    /// let mapped = non_empty.into_map(|item| item as i32 * -1);
     ///
-    /// Panics on capacity overflow.
-    pub fn repeat(&self, count: Size) -> NonEmptyVec<T> {
-        let repeated = self.as_slice().repeat(count.get());
+    /// assert_eq!(mapped, [-1, -2, -3]);
+    ///
+    /// assert_eq!(mapped.as_ptr().addr(), pointer);
+    /// ```
+    ///
+    /// [`map`]: NonEmptySlice::map
+    pub fn into_map<U, F: FnMut(T) -> U>(self, mut function: F) -> NonEmptyVec<U> {
+        if size_of::<T>() == 0 || Layout::new::<T>() != Layout::new::<U>() {
+            let vec = self.into_vec().into_iter().map(function).collect();
 
-        // SAFETY: repeating non-empty slice non-zero number of times yields non-empty vector
-        unsafe { NonEmptyVec::new_unchecked(repeated) }
-    }
-}
+            // SAFETY: mapping non-empty vector yields non-empty vector
+            return unsafe { NonEmptyVec::new_unchecked(vec) };
+        }
 
-impl<T: Clone> NonEmptySlice<T> {
-    /// Constructs [`Vec<T>`] from the slice via cloning.
-    pub fn to_vec(&self) -> Vec<T> {
-        self.as_slice().to_vec()
-    }
+        let mut vec = ManuallyDrop::new(self.into_vec());
 
-    /// Constructs [`NonEmptyVec<T>`] from the non-empty slice via cloning.
-    pub fn to_non_empty_vec(&self) -> NonEmptyVec<T> {
-        NonEmptyVec::from_non_empty_slice(self)
-    }
-}
+        let mut guard = MapGuard::<T, U> {
+            ptr: vec.as_mut_ptr(),
+            len: vec.len(),
+            capacity: vec.capacity(),
+            mapped: 0,
+            output: PhantomData,
+        };
 
-impl<T> NonEmptyVec<T> {
-    /// Checks if the vector is empty. Always returns [`false`].
-    ///
-    /// This method is marked as deprecated since the vector is never empty.
-    #[must_use]
-    #[deprecated = "this vector is never empty"]
-    pub const fn is_empty(&self) -> bool {
-        false
-    }
+        while guard.mapped < guard.len {
+            // SAFETY: the item at this index is initialized and is never read again;
+            // should the function panic, the guard drops the items after it
+            let item = unsafe { guard.ptr.add(guard.mapped).read() };
 
-    /// Returns the length of the vector as [`Size`].
-    #[must_use]
-    pub const fn len(&self) -> Size {
-        self.as_non_empty_slice().len()
-    }
+            let output = function(item);
 
-    /// Returns the capacity of the vector as [`Size`].
-    #[must_use]
-    pub const fn capacity(&self) -> Size {
-        let capacity = self.as_vec().capacity();
+            // SAFETY: the layouts of `T` and `U` match, and the slot was moved out of
+            unsafe { guard.ptr.add(guard.mapped).cast::<U>().write(output) };
 
-        // SAFETY: non-empty vector implies non-zero capacity
-        unsafe { Size::new_unchecked(capacity) }
-    }
+            guard.mapped += 1;
+        }
+
+        let guard = ManuallyDrop::new(guard);
+
+        // SAFETY: all items are mapped in place, and the layouts of `T` and `U` match,
+        // so the allocation is valid for `Vec<U>`; moreover, the length is non-zero
+        unsafe {
+            NonEmptyVec::new_unchecked(Vec::from_raw_parts(
+                guard.ptr.cast::<U>(),
+                guard.len,
+                guard.capacity,
+            ))
+        }
+    }
+
+    /// Similar to [`into_map`], but the function is fallible.
+    ///
+    /// Stops at the first error, dropping the remaining items.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by the function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let non_empty = non_empty_vec!["13".to_owned(), "42".to_owned()];
+    ///
+    /// let parsed = non_empty.into_try_map(|item| item.parse::<u8>()).unwrap();
+    ///
+    /// assert_eq!(parsed, [13, 42]);
+    ///
+    /// let invalid = non_empty_vec!["13".to_owned(), "nekit".to_owned()];
+    ///
+    /// assert!(invalid.into_try_map(|item| item.parse::<u8>()).is_err());
+    /// ```
+    ///
+    /// [`into_map`]: Self::into_map
+    pub fn into_try_map<U, E, F: FnMut(T) -> Result<U, E>>(
+        self,
+        function: F,
+    ) -> Result<NonEmptyVec<U>, E> {
+        // NOTE: this is not named `try_map` to avoid shadowing `NonEmptySlice::try_map`
+        let vec = self
+            .into_vec()
+            .into_iter()
+            .map(function)
+            .collect::<Result<_, _>>()?;
+
+        // SAFETY: mapping non-empty vector yields non-empty vector
+        Ok(unsafe { NonEmptyVec::new_unchecked(vec) })
+    }
+
+    /// Edits the contained [`Vec<T>`] using the given function, re-validating non-emptiness
+    /// afterwards, consuming [`Self`].
+    ///
+    /// This is the safe alternative to [`as_mut_vec`] for arbitrary modifications.
+    ///
+    /// Returns the edited vector along with the value returned from the function.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmptyVec<T>`] if the function leaves the vector empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let non_empty = non_empty_vec![1, 2, 3];
+    ///
+    /// let (edited, removed) = non_empty
+    ///     .edit(|vec| vec.extract_if(.., |item| *item < 2).count())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(edited, [2, 3]);
+    /// assert_eq!(removed, 1);
+    ///
+    /// assert!(edited.edit(|vec| vec.clear()).is_err());
+    /// ```
+    ///
+    /// [`as_mut_vec`]: Self::as_mut_vec
+    pub fn edit<R, F: FnOnce(&mut Vec<T>) -> R>(
+        self,
+        function: F,
+    ) -> Result<(Self, R), EmptyVec<T>> {
+        let mut vec = self.into_vec();
+
+        let output = function(&mut vec);
+
+        Self::new(vec).map(|non_empty| (non_empty, output))
+    }
+
+    /// Edits the contained [`Vec<T>`] using the given function, pushing the fallback value
+    /// if the function leaves the vector empty.
+    ///
+    /// The fallback is also pushed if the function panics after emptying the vector,
+    /// so the vector always remains non-empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let mut non_empty = non_empty_vec![1, 2, 3];
+    ///
+    /// let removed = non_empty.edit_or(0, |vec| vec.drain(..).count());
+    ///
+    /// assert_eq!(removed, 3);
+    ///
+    /// assert_eq!(non_empty, [0]);
+    /// ```
+    pub fn edit_or<R, F: FnOnce(&mut Vec<T>) -> R>(&mut self, fallback: T, function: F) -> R {
+        // NOTE: the fallback is taken upfront, since the vector has to be made non-empty again
+        // even when unwinding, and computing the fallback could panic itself
+
+        let guard = EditGuard {
+            // SAFETY: the guard pushes the fallback if the vector becomes empty
+            vec: unsafe { self.as_mut_vec() },
+            fallback: Some(fallback),
+        };
+
+        function(guard.vec)
+    }
+}
+
+// NOTE: in case the mapping function panics, the mapped items are dropped as `U`,
+// the ones not yet mapped are dropped as `T`, and the allocation is freed
+
+struct MapGuard<T, U> {
+    ptr: *mut T,
+    len: usize,
+    capacity: usize,
+    mapped: usize,
+    output: PhantomData<U>,
+}
+
+impl<T, U> Drop for MapGuard<T, U> {
+    fn drop(&mut self) {
+        // SAFETY: the first `mapped` items are initialized as `U`, the item at `mapped` was
+        // moved out of, and the rest are initialized as `T`; the allocation was made for `T`
+        unsafe {
+            ptr::slice_from_raw_parts_mut(self.ptr.cast::<U>(), self.mapped).drop_in_place();
+
+            let rest = self.mapped + 1;
+
+            if rest < self.len {
+                ptr::slice_from_raw_parts_mut(self.ptr.add(rest), self.len - rest).drop_in_place();
+            }
+
+            drop(Vec::from_raw_parts(self.ptr, 0, self.capacity));
+        }
+    }
+}
+
+struct EditGuard<'a, T> {
+    vec: &'a mut Vec<T>,
+    fallback: Option<T>,
+}
+
+impl<T> Drop for EditGuard<'_, T> {
+    fn drop(&mut self) {
+        if self.vec.is_empty() {
+            self.vec.extend(self.fallback.take());
+        }
+    }
+}
+
+impl<T: Clone> NonEmptyVec<T> {
+    /// Constructs [`Self`] from [`NonEmptySlice<T>`] via cloning.
+    ///
+    /// # Examples
+    ///
+    /// Basic snippet:
+    ///
+    /// ```
+    /// use non_empty_slice::{NonEmptyByteVec, NonEmptyBytes};
+    ///
+    /// let nekit = NonEmptyBytes::from_slice(b"nekit").unwrap();
+    ///
+    /// let owned = NonEmptyByteVec::from_non_empty_slice(nekit);
+    /// ```
+    pub fn from_non_empty_slice(non_empty: &NonEmptySlice<T>) -> Self {
+        // SAFETY: the slice is non-empty by construction
+        unsafe { Self::new_unchecked(non_empty.to_vec()) }
+    }
+}
+
+impl<S> NonEmptySlice<S> {
+    /// Flattens the non-empty slice of non-empty slices into [`NonEmptyVec<T>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::{NonEmptySlice, non_empty_slice};
+    ///
+    /// let parts = [non_empty_slice!(&[1, 2]), non_empty_slice!(&[3])];
+    ///
+    /// let non_empty = NonEmptySlice::from_slice(&parts).unwrap();
+    ///
+    /// assert_eq!(non_empty.concat().as_slice(), [1, 2, 3]);
+    /// ```
+    pub fn concat<T: Clone>(&self) -> NonEmptyVec<T>
+    where
+        S: Borrow<NonEmptySlice<T>>,
+    {
+        let (first, rest) = self.split_first();
+
+        let mut output = first.borrow().to_non_empty_vec();
+
+        for item in rest {
+            output.extend_from(item.borrow());
+        }
+
+        output
+    }
+
+    /// Flattens the non-empty slice of non-empty slices into [`NonEmptyVec<T>`],
+    /// placing the given separator between each of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::{NonEmptySlice, non_empty_slice};
+    ///
+    /// let parts = [non_empty_slice!(&[1, 2]), non_empty_slice!(&[3])];
+    ///
+    /// let non_empty = NonEmptySlice::from_slice(&parts).unwrap();
+    ///
+    /// assert_eq!(non_empty.join(&[0]).as_slice(), [1, 2, 0, 3]);
+    /// ```
+    pub fn join<T: Clone>(&self, separator: &[T]) -> NonEmptyVec<T>
+    where
+        S: Borrow<NonEmptySlice<T>>,
+    {
+        let (first, rest) = self.split_first();
+
+        let mut output = first.borrow().to_non_empty_vec();
+
+        for item in rest {
+            output.extend_from(separator);
+            output.extend_from(item.borrow());
+        }
+
+        output
+    }
+}
+
+#[cfg(feature = "std")]
+fn group_into<K: Eq + Hash, T, F: FnMut(&T) -> K>(
+    groups: &mut HashMap<K, NonEmptyVec<T>>,
+    item: T,
+    function: &mut F,
+) {
+    match groups.entry(function(&item)) {
+        Entry::Occupied(entry) => entry.into_mut().push(item),
+        Entry::Vacant(entry) => {
+            entry.insert(NonEmptyVec::single(item));
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> NonEmptySlice<T> {
+    /// Groups the items of the slice by the keys returned from the given function.
+    ///
+    /// Each group is non-empty, and the items are kept in their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3, 4, 5]);
+    ///
+    /// let groups = non_empty.group_by_key(|item| item % 2);
+    ///
+    /// assert_eq!(groups[&0], [&2, &4]);
+    /// assert_eq!(groups[&1], [&1, &3, &5]);
+    /// ```
+    pub fn group_by_key<K: Eq + Hash, F: FnMut(&T) -> K>(
+        &self,
+        mut function: F,
+    ) -> HashMap<K, NonEmptyVec<&T>> {
+        let mut groups = HashMap::new();
+
+        for item in self {
+            group_into(&mut groups, item, &mut |item: &&T| function(item));
+        }
+
+        groups
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> NonEmptyVec<T> {
+    /// Groups the items of the vector by the keys returned from the given function,
+    /// consuming the vector.
+    ///
+    /// Each group is non-empty, and the items are kept in their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let non_empty = non_empty_vec!["nekit", "dev", "rust", "non-empty"];
+    ///
+    /// let groups = non_empty.into_group_by_key(|item| item.len());
+    ///
+    /// assert_eq!(groups[&3], ["dev"]);
+    /// assert_eq!(groups[&4], ["rust"]);
+    /// ```
+    pub fn into_group_by_key<K: Eq + Hash, F: FnMut(&T) -> K>(
+        self,
+        mut function: F,
+    ) -> HashMap<K, NonEmptyVec<T>> {
+        let mut groups = HashMap::new();
+
+        for item in self {
+            group_into(&mut groups, item, &mut function);
+        }
+
+        groups
+    }
+}
+
+impl NonEmptyVec<String> {
+    /// Concatenates the strings into single [`String`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let parts = non_empty_vec!["nekit".to_owned(), "dev".to_owned()];
+    ///
+    /// assert_eq!(parts.concat(), "nekitdev");
+    /// ```
+    #[must_use]
+    pub fn concat(&self) -> String {
+        self.as_slice().concat()
+    }
+}
+
+impl<T> NonEmptySlice<T> {
+    /// Constructs [`NonEmptyVec<U>`] by applying the given function to each item of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&[1, 2, 3]);
+    ///
+    /// let doubled = non_empty.map(|item| item * 2);
+    ///
+    /// assert_eq!(doubled.as_slice(), [2, 4, 6]);
+    /// ```
+    pub fn map<U, F: FnMut(&T) -> U>(&self, function: F) -> NonEmptyVec<U> {
+        let vec = self.iter().map(function).collect();
+
+        // SAFETY: mapping non-empty slice yields non-empty vector
+        unsafe { NonEmptyVec::new_unchecked(vec) }
+    }
+
+    /// Similar to [`map`], but the function is fallible.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by the function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    ///
+    /// let non_empty = non_empty_slice!(&["13", "42"]);
+    ///
+    /// let parsed = non_empty.try_map(|item| item.parse::<u8>()).unwrap();
+    ///
+    /// assert_eq!(parsed.as_slice(), [13, 42]);
+    ///
+    /// let invalid = non_empty_slice!(&["13", "nekit"]);
+    ///
+    /// assert!(invalid.try_map(|item| item.parse::<u8>()).is_err());
+    /// ```
+    ///
+    /// [`map`]: Self::map
+    pub fn try_map<U, E, F: FnMut(&T) -> Result<U, E>>(
+        &self,
+        function: F,
+    ) -> Result<NonEmptyVec<U>, E> {
+        let vec = self.iter().map(function).collect::<Result<_, _>>()?;
+
+        // SAFETY: mapping non-empty slice yields non-empty vector
+        Ok(unsafe { NonEmptyVec::new_unchecked(vec) })
+    }
+}
+
+impl<T: Clone> NonEmptySlice<T> {
+    /// Constructs [`Vec<T>`] from the slice via cloning.
+    pub fn to_vec(&self) -> Vec<T> {
+        self.as_slice().to_vec()
+    }
+
+    /// Constructs [`NonEmptyVec<T>`] from the non-empty slice via cloning.
+    pub fn to_non_empty_vec(&self) -> NonEmptyVec<T> {
+        NonEmptyVec::from_non_empty_slice(self)
+    }
+
+    /// Creates [`NonEmptyVec<T>`] by repeating this non-empty slice certain number of times.
+    ///
+    /// # Panics
+    ///
+    /// Panics on capacity overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_slice;
+    /// use non_zero_size::const_size;
+    ///
+    /// let non_empty = non_empty_slice!(&["nekit", "dev"]);
+    ///
+    /// let repeated = non_empty.repeat(const_size!(2));
+    ///
+    /// assert_eq!(repeated.as_slice(), ["nekit", "dev", "nekit", "dev"]);
+    /// ```
+    pub fn repeat(&self, count: Size) -> NonEmptyVec<T> {
+        let capacity = self
+            .len()
+            .get()
+            .checked_mul(count.get())
+            .expect("capacity overflow");
+
+        let mut repeated = Vec::with_capacity(capacity);
+
+        for _ in 0..count.get() {
+            repeated.extend_from_slice(self.as_slice());
+        }
+
+        // SAFETY: repeating non-empty slice non-zero number of times yields non-empty vector
+        unsafe { NonEmptyVec::new_unchecked(repeated) }
+    }
+}
+
+impl NonEmptyBytes {
+    /// Returns [`NonEmptyByteVec`] containing the copy of the bytes,
+    /// where each byte is mapped to its ASCII upper case equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptyBytes;
+    ///
+    /// let nekit = NonEmptyBytes::from_slice(b"nekit").unwrap();
+    ///
+    /// assert_eq!(nekit.to_ascii_uppercase().as_slice(), b"NEKIT");
+    /// ```
+    #[must_use]
+    pub fn to_ascii_uppercase(&self) -> NonEmptyByteVec {
+        // SAFETY: mapping preserves the length, so the output is non-empty
+        unsafe { NonEmptyByteVec::new_unchecked(self.as_slice().to_ascii_uppercase()) }
+    }
+
+    /// Returns [`NonEmptyByteVec`] containing the copy of the bytes,
+    /// where each byte is mapped to its ASCII lower case equivalent.
+    #[must_use]
+    pub fn to_ascii_lowercase(&self) -> NonEmptyByteVec {
+        // SAFETY: mapping preserves the length, so the output is non-empty
+        unsafe { NonEmptyByteVec::new_unchecked(self.as_slice().to_ascii_lowercase()) }
+    }
+}
+
+impl NonEmptyByteVec {
+    /// Similar to [`NonEmptyBytes::take`], but consumes the vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns the vector back if there are less than `count` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptyByteVec;
+    /// use non_zero_size::const_size;
+    ///
+    /// let input = NonEmptyByteVec::new(b"GET /".to_vec()).unwrap();
+    ///
+    /// let (method, rest) = input.into_take(const_size!(3)).unwrap();
+    ///
+    /// assert_eq!(method, *b"GET");
+    /// assert_eq!(rest, b" /");
+    /// ```
+    pub fn into_take(mut self, count: Size) -> Result<(Self, Vec<u8>), Self> {
+        if count > self.len() {
+            return Err(self);
+        }
+
+        let rest = self.split_off(count);
+
+        Ok((self, rest))
+    }
+
+    /// Similar to [`NonEmptyBytes::take_last`], but consumes the vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns the vector back if there are less than `count` bytes.
+    pub fn into_take_last(mut self, count: Size) -> Result<(Vec<u8>, Self), Self> {
+        let Some(index) = self.len().get().checked_sub(count.get()) else {
+            return Err(self);
+        };
+
+        let Some(index) = Size::new(index) else {
+            return Ok((Vec::new(), self));
+        };
+
+        let taken = self.split_off(index);
+
+        // SAFETY: `taken` contains `count` bytes, which is non-zero
+        let non_empty = unsafe { Self::new_unchecked(taken) };
+
+        Ok((self.into_vec(), non_empty))
+    }
+}
+
+impl<T> NonEmptyVec<T> {
+    /// Checks if the vector is empty. Always returns [`false`].
+    ///
+    /// This method is marked as deprecated since the vector is never empty.
+    #[must_use]
+    #[deprecated = "this vector is never empty"]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the length of the vector as [`Size`].
+    #[must_use]
+    pub const fn len(&self) -> Size {
+        self.as_non_empty_slice().len()
+    }
+
+    /// Returns the capacity of the vector as [`Size`].
+    #[must_use]
+    pub const fn capacity(&self) -> Size {
+        let capacity = self.as_vec().capacity();
+
+        // SAFETY: non-empty vector implies non-zero capacity
+        unsafe { Size::new_unchecked(capacity) }
+    }
 
     /// Appends the given value to the end of the vector.
     ///
     /// # Panics
     ///
     /// Panics on capacity overflow.
-    pub fn push(&mut self, value: T) {
-        // SAFETY: pushing can not make the vector empty
+    pub fn push(&mut self, value: T) {
+        // SAFETY: pushing can not make the vector empty
+        unsafe {
+            self.as_mut_vec().push(value);
+        }
+    }
+
+    /// Appends the given value to the end of the vector if there is sufficient spare capacity,
+    /// otherwise returns the value back.
+    ///
+    /// This method never reallocates.
+    ///
+    /// # Errors
+    ///
+    /// Returns the value back if the vector is at full capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    /// use non_zero_size::const_size;
+    ///
+    /// let mut vec = non_empty_vec![1];
+    ///
+    /// vec.reserve_exact(const_size!(1));
+    ///
+    /// assert_eq!(vec.push_within_capacity(2), Ok(()));
+    ///
+    /// vec.shrink_to_fit();
+    ///
+    /// assert_eq!(vec.push_within_capacity(3), Err(3));
+    ///
+    /// assert_eq!(vec, [1, 2]);
+    /// ```
+    pub fn push_within_capacity(&mut self, value: T) -> Result<(), T> {
+        // NOTE: `Vec::push_within_capacity` is unstable, so we check the capacity ourselves
+
+        if self.len() == self.capacity() {
+            return Err(value);
+        }
+
+        self.push(value);
+
+        Ok(())
+    }
+
+    /// Inserts the given value at the specified index if there is sufficient spare capacity,
+    /// otherwise returns the value back.
+    ///
+    /// This method never reallocates.
+    ///
+    /// # Errors
+    ///
+    /// Returns the value back if the vector is at full capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    /// use non_zero_size::const_size;
+    ///
+    /// let mut vec = non_empty_vec![1, 3];
+    ///
+    /// vec.reserve_exact(const_size!(1));
+    ///
+    /// assert_eq!(vec.insert_within_capacity(1, 2), Ok(()));
+    ///
+    /// vec.shrink_to_fit();
+    ///
+    /// assert_eq!(vec.insert_within_capacity(0, 0), Err(0));
+    ///
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    pub fn insert_within_capacity(&mut self, index: usize, value: T) -> Result<(), T> {
+        let len = self.len().get();
+
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        if len == self.capacity().get() {
+            return Err(value);
+        }
+
+        self.insert(index, value);
+
+        Ok(())
+    }
+
+    /// Extends the vector with exactly one value.
+    ///
+    /// This is the inherent counterpart of the unstable [`Extend::extend_one`],
+    /// and is equivalent to [`push`].
+    ///
+    /// # Panics
+    ///
+    /// Panics on capacity overflow.
+    ///
+    /// [`push`]: Self::push
+    pub fn extend_one(&mut self, value: T) {
+        self.push(value);
+    }
+
+    /// Reserves capacity in the vector for the given number of additional values.
+    ///
+    /// This is the inherent counterpart of the unstable [`Extend::extend_reserve`].
+    ///
+    /// Unlike [`reserve`], the additional capacity is allowed to be zero,
+    /// which makes this method suitable for lower bounds of size hints.
+    ///
+    /// # Panics
+    ///
+    /// Panics on capacity overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let mut non_empty = non_empty_vec![1];
+    ///
+    /// let iterator = 2..=5;
+    ///
+    /// non_empty.extend_reserve(iterator.size_hint().0);
+    ///
+    /// assert!(non_empty.capacity().get() >= 5);
+    ///
+    /// for value in iterator {
+    ///     non_empty.extend_one(value);
+    /// }
+    ///
+    /// assert_eq!(non_empty, [1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// [`reserve`]: Self::reserve
+    pub fn extend_reserve(&mut self, additional: usize) {
+        // SAFETY: reserving can not make the vector empty
+        unsafe {
+            self.as_mut_vec().reserve(additional);
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more values to be inserted into the vector.
+    ///
+    /// Note that the additional capacity is required to be non-zero via [`Size`].
+    ///
+    /// This method can over-allocate to speculatively avoid frequent reallocations.
+    ///
+    /// Does nothing if the capacity is already sufficient.
+    ///
+    /// # Panics
+    ///
+    /// Panics on capacity overflow.
+    pub fn reserve(&mut self, additional: Size) {
+        // SAFETY: reserving can not make the vector empty
+        unsafe {
+            self.as_mut_vec().reserve(additional.get());
+        }
+    }
+
+    /// Reserves the minimum capacity for exactly `additional` more values to be inserted
+    /// into the vector.
+    ///
+    /// Note that the additional capacity is required to be non-zero via [`Size`].
+    ///
+    /// Unlike [`reserve`], this method will not deliberately over-allocate
+    /// to speculatively avoid frequent reallocations.
+    ///
+    /// Does nothing if the capacity is already sufficient.
+    ///
+    /// # Panics
+    ///
+    /// Panics on capacity overflow.
+    ///
+    /// [`reserve`]: Self::reserve
+    pub fn reserve_exact(&mut self, additional: Size) {
+        // SAFETY: reserving can not make the vector empty
+        unsafe {
+            self.as_mut_vec().reserve_exact(additional.get());
+        }
+    }
+
+    /// Tries to reserve capacity for at least `additional` more values to be inserted
+    /// into the vector.
+    ///
+    /// Note that the additional capacity is required to be non-zero via [`Size`].
+    ///
+    /// This method can over-allocate to speculatively avoid frequent reallocations.
+    ///
+    /// Does nothing if the capacity is already sufficient.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryReserveError`] if the allocation fails or capacity overflows.
+    pub fn try_reserve(&mut self, additional: Size) -> Result<(), TryReserveError> {
+        // SAFETY: reserving can not make the vector empty
+        unsafe { self.as_mut_vec().try_reserve(additional.get()) }
+    }
+
+    /// Tries to reserve the minimum capacity for exactly `additional` more values
+    /// to be inserted into the vector.
+    ///
+    /// Note that the additional capacity is required to be non-zero via [`Size`].
+    ///
+    /// Unlike [`try_reserve`], this method will not deliberately over-allocate
+    /// to speculatively avoid frequent reallocations.
+    ///
+    /// Does nothing if the capacity is already sufficient.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryReserveError`] if the allocation fails or capacity overflows.
+    ///
+    /// [`try_reserve`]: Self::try_reserve
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        // SAFETY: reserving can not make the vector empty
+        unsafe { self.as_mut_vec().try_reserve_exact(additional) }
+    }
+
+    /// Shrinks the capacity of the vector as much as possible.
+    pub fn shrink_to_fit(&mut self) {
+        // SAFETY: shrinking can not make the vector empty
+        unsafe {
+            self.as_mut_vec().shrink_to_fit();
+        }
+    }
+
+    /// Shrinks the capacity of the vector to the specified amount.
+    ///
+    /// The capacity will remain at least as large as both the length and the supplied amount.
+    ///
+    /// Does nothing if the current capacity is less than or equal to the given amount.
+    pub fn shrink_to(&mut self, capacity: Size) {
+        // SAFETY: shrinking can not make the vector empty
+        unsafe {
+            self.as_mut_vec().shrink_to(capacity.get());
+        }
+    }
+
+    /// Shortens the vector, keeping the first `len` items and dropping the rest.
+    pub fn truncate(&mut self, len: Size) {
+        // SAFETY: length provided is non-zero, so truncating can not make the vector empty
+        unsafe {
+            self.as_mut_vec().truncate(len.get());
+        }
+    }
+
+    /// Shortens the vector, keeping the first `len` items and dropping the rest,
+    /// provided `len` is non-zero, returning whether the operation was applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let mut non_empty = non_empty_vec![1, 2, 3];
+    ///
+    /// assert!(!non_empty.try_truncate(0));
+    /// assert!(non_empty.try_truncate(2));
+    ///
+    /// assert_eq!(non_empty, [1, 2]);
+    /// ```
+    pub fn try_truncate(&mut self, len: usize) -> bool {
+        Size::new(len).map(|len| self.truncate(len)).is_some()
+    }
+
+    /// Shortens the vector to its first item, dropping the rest.
+    ///
+    /// This is the non-empty analogue of [`Vec::clear`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let mut vec = non_empty_vec![1, 2, 3];
+    ///
+    /// vec.keep_first();
+    ///
+    /// assert_eq!(vec, [1]);
+    /// ```
+    pub fn keep_first(&mut self) {
+        self.truncate(Size::MIN);
+    }
+
+    /// Shortens the vector to its last item, dropping the rest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let mut vec = non_empty_vec![1, 2, 3];
+    ///
+    /// vec.keep_last();
+    ///
+    /// assert_eq!(vec, [3]);
+    /// ```
+    pub fn keep_last(&mut self) {
+        let last = self.len().get() - 1;
+
+        // SAFETY: draining keeps the last item, so the vector remains non-empty
+        unsafe {
+            self.as_mut_vec().drain(..last);
+        }
+    }
+
+    /// Replaces the contents of the vector with the single given value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let mut vec = non_empty_vec![1, 2, 3];
+    ///
+    /// vec.clear_to(13);
+    ///
+    /// assert_eq!(vec, [13]);
+    /// ```
+    pub fn clear_to(&mut self, value: T) {
+        let len = self.len().get();
+
+        // NOTE: pushing first ensures the vector stays non-empty even if dropping panics
+
+        // SAFETY: the value is pushed before draining, so the vector remains non-empty
+        unsafe {
+            let vec = self.as_mut_vec();
+
+            vec.push(value);
+            vec.drain(..len);
+        }
+    }
+
+    /// Moves all the items out of `other` into `self`, leaving `other` empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics on capacity overflow.
+    pub fn append(&mut self, other: &mut Vec<T>) {
+        // SAFETY: appending can not make the vector empty
         unsafe {
-            self.as_mut_vec().push(value);
+            self.as_mut_vec().append(other);
         }
     }
 
-    /// Reserves capacity for at least `additional` more values to be inserted into the vector.
+    /// Inserts the given value at the specified index, shifting all items after it to the right.
     ///
-    /// Note that the additional capacity is required to be non-zero via [`Size`].
+    /// # Panics
     ///
-    /// This method can over-allocate to speculatively avoid frequent reallocations.
+    /// Panics if the index is out of bounds.
+    pub fn insert(&mut self, index: usize, value: T) {
+        // SAFETY: inserting can not make the vector empty
+        unsafe {
+            self.as_mut_vec().insert(index, value);
+        }
+    }
+
+    /// Checks whether the vector is almost empty, meaning it only contains one value.
+    #[must_use]
+    pub fn next_empty(&self) -> bool {
+        self.len() == Size::MIN
+    }
+
+    /// The negated version of [`next_empty`].
     ///
-    /// Does nothing if the capacity is already sufficient.
+    /// [`next_empty`]: Self::next_empty
+    #[must_use]
+    pub fn next_non_empty(&self) -> bool {
+        !self.next_empty()
+    }
+
+    /// Peeks at the last item of the vector mutably.
+    pub const fn peek_mut(&mut self) -> PeekMut<'_, T> {
+        PeekMut::new(self)
+    }
+
+    /// Removes the last item from the vector and returns it,
+    /// or [`None`] if the vector would become empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.next_non_empty()
+            // SAFETY: popping only if the vector would remain non-empty
+            .then(|| unsafe { self.as_mut_vec().pop() })
+            .flatten()
+    }
+
+    /// Removes the last item from the vector if the predicate returns [`true`],
+    /// or [`None`] if [`false`] is returned or if the vector would become empty.
+    pub fn pop_if<P: FnOnce(&mut T) -> bool>(&mut self, predicate: P) -> Option<T> {
+        self.next_non_empty()
+            // SAFETY: popping only if the vector would remain non-empty
+            .then(|| unsafe { self.as_mut_vec().pop_if(predicate) })
+            .flatten()
+    }
+
+    /// Removes and returns the item at the given index within the vector,
+    /// shifting all items after it to the left.
+    ///
+    /// Returns [`None`] if the vector would become empty.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        self.next_non_empty()
+            // SAFETY: removing only if the vector would remain non-empty
+            .then(|| unsafe { self.as_mut_vec().remove(index) })
+    }
+
+    /// Removes and returns the item at the given index within the vector,
+    /// replacing it with the last item of the vector.
+    ///
+    /// Returns [`None`] if the vector would become empty.
+    pub fn swap_remove(&mut self, index: usize) -> Option<T> {
+        self.next_non_empty()
+            // SAFETY: swap-removing only if the vector would remain non-empty
+            .then(|| unsafe { self.as_mut_vec().swap_remove(index) })
+    }
+
+    /// Removes and returns the item at the given index within the vector,
+    /// shifting all items after it to the left, without checking if the vector
+    /// would become empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the vector would remain non-empty,
+    /// that is, it contains more than one item.
     ///
     /// # Panics
     ///
-    /// Panics on capacity overflow.
-    pub fn reserve(&mut self, additional: Size) {
-        // SAFETY: reserving can not make the vector empty
-        unsafe {
-            self.as_mut_vec().reserve(additional.get());
-        }
+    /// Panics if the index is out of bounds.
+    pub unsafe fn remove_unchecked(&mut self, index: usize) -> T {
+        // SAFETY: the caller must ensure that the vector would remain non-empty
+        unsafe { self.as_mut_vec().remove(index) }
     }
 
-    /// Reserves the minimum capacity for exactly `additional` more values to be inserted
-    /// into the vector.
+    /// Removes and returns the item at the given index within the vector,
+    /// replacing it with the last item of the vector, without checking if the vector
+    /// would become empty.
     ///
-    /// Note that the additional capacity is required to be non-zero via [`Size`].
+    /// # Safety
     ///
-    /// Unlike [`reserve`], this method will not deliberately over-allocate
-    /// to speculatively avoid frequent reallocations.
+    /// The caller must ensure that the vector would remain non-empty,
+    /// that is, it contains more than one item.
     ///
-    /// Does nothing if the capacity is already sufficient.
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub unsafe fn swap_remove_unchecked(&mut self, index: usize) -> T {
+        // SAFETY: the caller must ensure that the vector would remain non-empty
+        unsafe { self.as_mut_vec().swap_remove(index) }
+    }
+
+    /// Removes and returns the item at the given index within the vector,
+    /// shifting all items after it to the left.
+    ///
+    /// If the vector would become empty, the item is replaced with `replacement` instead,
+    /// therefore this method never fails.
     ///
     /// # Panics
     ///
-    /// Panics on capacity overflow.
+    /// Panics if the index is out of bounds.
     ///
-    /// [`reserve`]: Self::reserve
-    pub fn reserve_exact(&mut self, additional: Size) {
-        // SAFETY: reserving can not make the vector empty
-        unsafe {
-            self.as_mut_vec().reserve_exact(additional.get());
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let mut queue = non_empty_vec![1, 2];
+    ///
+    /// assert_eq!(queue.remove_or_replace(0, 0), 1);
+    /// assert_eq!(queue.remove_or_replace(0, 0), 2);
+    ///
+    /// assert_eq!(queue, [0]);
+    /// ```
+    pub fn remove_or_replace(&mut self, index: usize, replacement: T) -> T {
+        if self.next_empty() {
+            return replace(&mut self[index], replacement);
         }
+
+        // SAFETY: removing only if the vector would remain non-empty
+        unsafe { self.remove_unchecked(index) }
     }
 
-    /// Tries to reserve capacity for at least `additional` more values to be inserted
-    /// into the vector.
+    /// Removes and returns the item at the given index within the vector,
+    /// replacing it with the last item of the vector.
     ///
-    /// Note that the additional capacity is required to be non-zero via [`Size`].
+    /// If the vector would become empty, the item is replaced with `replacement` instead,
+    /// therefore this method never fails.
     ///
-    /// This method can over-allocate to speculatively avoid frequent reallocations.
+    /// # Panics
     ///
-    /// Does nothing if the capacity is already sufficient.
+    /// Panics if the index is out of bounds.
+    pub fn swap_remove_or_replace(&mut self, index: usize, replacement: T) -> T {
+        if self.next_empty() {
+            return replace(&mut self[index], replacement);
+        }
+
+        // SAFETY: swap-removing only if the vector would remain non-empty
+        unsafe { self.swap_remove_unchecked(index) }
+    }
+
+    /// Appends the given value to the back of the vector and removes the first item,
+    /// returning it.
     ///
-    /// # Errors
+    /// The length of the vector remains unchanged. Note that removing the first item
+    /// shifts all the others, so this method is `O(n)`; consider `VecDeque<T>`
+    /// for sliding windows advanced in hot loops.
     ///
-    /// Returns [`TryReserveError`] if the allocation fails or capacity overflows.
-    pub fn try_reserve(&mut self, additional: Size) -> Result<(), TryReserveError> {
-        // SAFETY: reserving can not make the vector empty
-        unsafe { self.as_mut_vec().try_reserve(additional.get()) }
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let mut window = non_empty_vec![1, 2, 3];
+    ///
+    /// assert_eq!(window.push_shift(4), 1);
+    ///
+    /// assert_eq!(window, [2, 3, 4]);
+    /// ```
+    pub fn push_shift(&mut self, value: T) -> T {
+        self.push(value);
+
+        // SAFETY: the vector contains at least two items after pushing,
+        // so removing the first one can not make it empty
+        unsafe { self.as_mut_vec().remove(0) }
     }
 
-    /// Tries to reserve the minimum capacity for exactly `additional` more values
-    /// to be inserted into the vector.
+    /// Splits the vector into two at the given non-zero index.
+    ///
+    /// The index has to be non-zero to guarantee the vector would remain non-empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided index is out of bounds.
+    pub fn split_off(&mut self, at: Size) -> Vec<T> {
+        // SAFETY: splitting at non-zero index can not make the vector empty
+        unsafe { self.as_mut_vec().split_off(at.get()) }
+    }
+
+    /// Splits the vector into two at the given non-zero index, provided both halves
+    /// are non-empty, returning the tail as [`NonEmptyVec<T>`].
+    ///
+    /// Returns [`None`] if the index is greater than or equal to the length of the vector,
+    /// leaving the vector unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::{const_size, non_empty_vec};
+    ///
+    /// let mut non_empty = non_empty_vec![1, 2, 3];
+    ///
+    /// assert!(non_empty.split_off_non_empty(const_size!(3)).is_none());
+    ///
+    /// let tail = non_empty.split_off_non_empty(const_size!(1)).unwrap();
+    ///
+    /// assert_eq!(non_empty, [1]);
+    /// assert_eq!(tail, [2, 3]);
+    /// ```
+    pub fn split_off_non_empty(&mut self, at: Size) -> Option<Self> {
+        (at < self.len()).then(|| {
+            let tail = self.split_off(at);
+
+            // SAFETY: the index is less than the length, so the tail is non-empty
+            unsafe { Self::new_unchecked(tail) }
+        })
+    }
+
+    /// Consumes the vector, returning the first item and the rest of the items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let (first, rest) = non_empty_vec![1, 2, 3].split_off_first();
+    ///
+    /// assert_eq!(first, 1);
+    /// assert_eq!(rest, [2, 3]);
+    /// ```
+    pub fn split_off_first(self) -> (T, Vec<T>) {
+        let mut vec = self.into_vec();
+
+        let first = vec.remove(0);
+
+        (first, vec)
+    }
+
+    /// Consumes the vector, returning the rest of the items and the last item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let (rest, last) = non_empty_vec![1, 2, 3].split_off_last();
+    ///
+    /// assert_eq!(rest, [1, 2]);
+    /// assert_eq!(last, 3);
+    /// ```
+    pub fn split_off_last(self) -> (Vec<T>, T) {
+        let mut vec = self.into_vec();
+
+        // SAFETY: the vector is non-empty by construction, so there is always some last item
+        let last = unsafe { vec.pop().unwrap_unchecked() };
+
+        (vec, last)
+    }
+
+    /// Checks whether the given range covers the entire vector.
+    fn covers<R: RangeBounds<usize>>(&self, range: &R) -> bool {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len().get(),
+        };
+
+        start == 0 && end == self.len().get()
+    }
+
+    /// Removes the specified range from the vector in bulk,
+    /// returning all removed items.
+    ///
+    /// Returns [`None`] if the vector would become empty, that is,
+    /// if the range covers the entire vector.
+    ///
+    /// Unlike [`Vec::drain`], the removed items are collected eagerly, which ensures
+    /// the vector can not become empty even if the returned value is leaked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point
+    /// or if the end point is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let mut non_empty = non_empty_vec![1, 2, 3];
+    ///
+    /// assert!(non_empty.drain(..).is_none());
+    ///
+    /// let drained = non_empty.drain(1..).unwrap();
+    ///
+    /// assert_eq!(drained, [2, 3]);
+    /// assert_eq!(non_empty.as_slice(), [1]);
+    /// ```
+    ///
+    /// Leaking the removed items does not affect the vector:
+    ///
+    /// ```
+    /// use core::mem::forget;
+    ///
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let mut non_empty = non_empty_vec![1, 2, 3];
+    ///
+    /// forget(non_empty.drain(..1).unwrap());
+    ///
+    /// assert_eq!(non_empty.as_slice(), [2, 3]);
+    /// assert_eq!(non_empty.len().get(), 2);
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Option<Vec<T>> {
+        // NOTE: `Drain` truncates the vector upon creation, and leaking it is safe,
+        // so the removed items are collected before the vector is observable again
+        (!self.covers(&range))
+            // SAFETY: draining only if the vector would remain non-empty
+            .then(|| unsafe { self.as_mut_vec().drain(range).collect() })
+    }
+
+    /// Replaces the specified range in the vector with the given non-empty iterator,
+    /// returning all removed items.
+    ///
+    /// Since the replacement is non-empty, the vector is guaranteed to remain non-empty.
+    ///
+    /// Unlike [`Vec::splice`], the removed items are collected eagerly, which ensures
+    /// the vector can not become empty even if the returned value is leaked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point
+    /// or if the end point is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let mut non_empty = non_empty_vec![1, 2, 3];
+    ///
+    /// let removed = non_empty.splice(.., non_empty_vec![4, 5]);
+    ///
+    /// assert_eq!(removed, [1, 2, 3]);
+    /// assert_eq!(non_empty, [4, 5]);
+    /// ```
+    pub fn splice<R: RangeBounds<usize>, I: IntoNonEmptyIterator<Item = T>>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Vec<T> {
+        // NOTE: the first item is obtained upfront, so that the vector remains non-empty
+        // even if the replacement panics
+        let (first, rest) = replace_with.into_non_empty_iter().consume();
+
+        let replacement = once(first).chain(rest);
+
+        // SAFETY: splicing non-empty replacement can not make the vector empty
+        unsafe { self.as_mut_vec().splice(range, replacement).collect() }
+    }
+
+    /// Similar to [`splice`], except the replacement is allowed to be empty.
+    ///
+    /// Returns [`None`] if the vector would become empty, that is,
+    /// if the range covers the entire vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point
+    /// or if the end point is greater than the length of the vector.
+    ///
+    /// [`splice`]: Self::splice
+    pub fn splice_checked<R: RangeBounds<usize>, I: IntoIterator<Item = T>>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Option<Vec<T>> {
+        (!self.covers(&range))
+            // SAFETY: splicing only if the retained part of the vector is non-empty
+            .then(|| unsafe { self.as_mut_vec().splice(range, replace_with).collect() })
+    }
+
+    /// Retains only the items specified by the predicate, consuming the vector.
     ///
-    /// Note that the additional capacity is required to be non-zero via [`Size`].
+    /// # Errors
     ///
-    /// Unlike [`try_reserve`], this method will not deliberately over-allocate
-    /// to speculatively avoid frequent reallocations.
+    /// Returns [`EmptyVec<T>`] if the predicate rejects every item.
     ///
-    /// Does nothing if the capacity is already sufficient.
+    /// # Examples
     ///
-    /// # Errors
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
     ///
-    /// Returns [`TryReserveError`] if the allocation fails or capacity overflows.
+    /// let non_empty = non_empty_vec![1, 2, 3, 4];
     ///
-    /// [`try_reserve`]: Self::try_reserve
-    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
-        // SAFETY: reserving can not make the vector empty
-        unsafe { self.as_mut_vec().try_reserve_exact(additional) }
-    }
+    /// let even = non_empty.retain(|value| value % 2 == 0).unwrap();
+    ///
+    /// assert_eq!(even.as_slice(), [2, 4]);
+    ///
+    /// let empty = even.retain(|value| value % 2 != 0).unwrap_err();
+    ///
+    /// assert!(empty.get().is_empty());
+    /// ```
+    pub fn retain<P: FnMut(&T) -> bool>(self, predicate: P) -> Result<Self, EmptyVec<T>> {
+        let mut vec = self.into_vec();
 
-    /// Shrinks the capacity of the vector as much as possible.
-    pub fn shrink_to_fit(&mut self) {
-        // SAFETY: shrinking can not make the vector empty
-        unsafe {
-            self.as_mut_vec().shrink_to_fit();
-        }
+        vec.retain(predicate);
+
+        Self::new(vec)
     }
 
-    /// Shrinks the capacity of the vector to the specified amount.
+    /// Retains only the items specified by the predicate, consuming the vector.
     ///
-    /// The capacity will remain at least as large as both the length and the supplied amount.
+    /// The predicate provided receives mutable references to the items.
     ///
-    /// Does nothing if the current capacity is less than or equal to the given amount.
-    pub fn shrink_to(&mut self, capacity: Size) {
-        // SAFETY: shrinking can not make the vector empty
-        unsafe {
-            self.as_mut_vec().shrink_to(capacity.get());
-        }
-    }
+    /// # Errors
+    ///
+    /// Returns [`EmptyVec<T>`] if the predicate rejects every item.
+    pub fn retain_mut<P: FnMut(&mut T) -> bool>(self, predicate: P) -> Result<Self, EmptyVec<T>> {
+        let mut vec = self.into_vec();
 
-    /// Shortens the vector, keeping the first `len` items and dropping the rest.
-    pub fn truncate(&mut self, len: Size) {
-        // SAFETY: length provided is non-zero, so truncating can not make the vector empty
-        unsafe {
-            self.as_mut_vec().truncate(len.get());
-        }
+        vec.retain_mut(predicate);
+
+        Self::new(vec)
     }
 
-    /// Moves all the items out of `other` into `self`, leaving `other` empty.
+    /// Retains only the items specified by the predicate, provided at least one item
+    /// is retained, returning whether the operation was applied.
     ///
-    /// # Panics
+    /// Non-emptiness is checked before modifying the vector, so if the predicate rejects
+    /// every item, the vector is left unchanged. The predicate is called exactly once
+    /// for each item, in order.
     ///
-    /// Panics on capacity overflow.
-    pub fn append(&mut self, other: &mut Vec<T>) {
-        // SAFETY: appending can not make the vector empty
-        unsafe {
-            self.as_mut_vec().append(other);
-        }
-    }
-
-    /// Inserts the given value at the specified index, shifting all items after it to the right.
+    /// # Examples
     ///
-    /// # Panics
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
     ///
-    /// Panics if the index is out of bounds.
-    pub fn insert(&mut self, index: usize, value: T) {
-        // SAFETY: inserting can not make the vector empty
-        unsafe {
-            self.as_mut_vec().insert(index, value);
-        }
-    }
-
-    /// Checks whether the vector is almost empty, meaning it only contains one value.
-    #[must_use]
-    pub fn next_empty(&self) -> bool {
-        self.len() == Size::MIN
+    /// let mut non_empty = non_empty_vec![1, 2, 3, 4];
+    ///
+    /// assert!(non_empty.try_retain(|value| value % 2 == 0));
+    ///
+    /// assert_eq!(non_empty, [2, 4]);
+    ///
+    /// assert!(!non_empty.try_retain(|value| value % 2 != 0));
+    ///
+    /// assert_eq!(non_empty, [2, 4]);
+    /// ```
+    pub fn try_retain<P: FnMut(&T) -> bool>(&mut self, mut predicate: P) -> bool {
+        self.try_retain_mut(|item| predicate(item))
     }
 
-    /// The negated version of [`next_empty`].
+    /// Similar to [`try_retain`], except the predicate receives mutable references to the items.
     ///
-    /// [`next_empty`]: Self::next_empty
-    #[must_use]
-    pub fn next_non_empty(&self) -> bool {
-        !self.next_empty()
-    }
+    /// [`try_retain`]: Self::try_retain
+    pub fn try_retain_mut<P: FnMut(&mut T) -> bool>(&mut self, mut predicate: P) -> bool {
+        let Some(first) = self.iter_mut().position(&mut predicate) else {
+            return false;
+        };
 
-    /// Peeks at the last item of the vector mutably.
-    pub const fn peek_mut(&mut self) -> PeekMut<'_, T> {
-        PeekMut::new(self)
-    }
+        let mut index = 0;
 
-    /// Removes the last item from the vector and returns it,
-    /// or [`None`] if the vector would become empty.
-    pub fn pop(&mut self) -> Option<T> {
-        self.next_non_empty()
-            // SAFETY: popping only if the vector would remain non-empty
-            .then(|| unsafe { self.as_mut_vec().pop() })
-            .flatten()
-    }
+        // SAFETY: the item at `first` is always retained, so the vector remains non-empty
+        unsafe {
+            self.as_mut_vec().retain_mut(|item| {
+                // NOTE: the items up to `first` were already checked, so the predicate
+                // is only called on the items after it
 
-    /// Removes the last item from the vector if the predicate returns [`true`],
-    /// or [`None`] if [`false`] is returned or if the vector would become empty.
-    pub fn pop_if<P: FnOnce(&mut T) -> bool>(&mut self, predicate: P) -> Option<T> {
-        self.next_non_empty()
-            // SAFETY: popping only if the vector would remain non-empty
-            .then(|| unsafe { self.as_mut_vec().pop_if(predicate) })
-            .flatten()
-    }
+                let keep = match index.cmp(&first) {
+                    Ordering::Less => false,
+                    Ordering::Equal => true,
+                    Ordering::Greater => predicate(item),
+                };
 
-    /// Removes and returns the item at the given index within the vector,
-    /// shifting all items after it to the left.
-    ///
-    /// Returns [`None`] if the vector would become empty.
-    pub fn remove(&mut self, index: usize) -> Option<T> {
-        self.next_non_empty()
-            // SAFETY: removing only if the vector would remain non-empty
-            .then(|| unsafe { self.as_mut_vec().remove(index) })
-    }
+                index += 1;
 
-    /// Removes and returns the item at the given index within the vector,
-    /// replacing it with the last item of the vector.
-    ///
-    /// Returns [`None`] if the vector would become empty.
-    pub fn swap_remove(&mut self, index: usize) -> Option<T> {
-        self.next_non_empty()
-            // SAFETY: swap-removing only if the vector would remain non-empty
-            .then(|| unsafe { self.as_mut_vec().swap_remove(index) })
-    }
+                keep
+            });
+        }
 
-    /// Splits the vector into two at the given non-zero index.
-    ///
-    /// The index has to be non-zero to guarantee the vector would remain non-empty.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the provided index is out of bounds.
-    pub fn split_off(&mut self, at: Size) -> Vec<T> {
-        // SAFETY: splitting at non-zero index can not make the vector empty
-        unsafe { self.as_mut_vec().split_off(at.get()) }
+        true
     }
 
     /// Resizes the vector in-place so that its length is equal to `new`.
@@ -797,6 +2363,185 @@ impl<T> NonEmptyVec<T> {
             self.as_mut_vec().dedup_by_key(function);
         }
     }
+
+    /// Groups consecutive equal items of the vector, consuming it.
+    ///
+    /// Grouping non-empty vector always yields non-empty vector of non-empty groups.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let groups = non_empty_vec![1, 1, 2, 3, 3, 3, 1].into_grouped();
+    ///
+    /// assert_eq!(groups.len().get(), 4);
+    ///
+    /// assert_eq!(groups.first(), &[1, 1]);
+    /// assert_eq!(groups.last(), &[1]);
+    /// ```
+    pub fn into_grouped(self) -> NonEmptyVec<Self>
+    where
+        T: PartialEq,
+    {
+        self.into_grouped_by(T::eq)
+    }
+
+    /// Groups consecutive items of the vector, as determined by the given predicate,
+    /// consuming the vector.
+    ///
+    /// The predicate receives the last item of the current group and the next item,
+    /// returning whether the latter belongs to the same group.
+    pub fn into_grouped_by<P: FnMut(&T, &T) -> bool>(self, mut predicate: P) -> NonEmptyVec<Self> {
+        let (first, rest) = self.consume();
+
+        let mut group = Self::single(first);
+
+        let mut groups = Vec::new();
+
+        for item in rest {
+            if predicate(group.last(), &item) {
+                group.push(item);
+            } else {
+                groups.push(replace(&mut group, Self::single(item)));
+            }
+        }
+
+        groups.push(group);
+
+        // SAFETY: the last group is always pushed, so the vector is non-empty
+        unsafe { NonEmptyVec::new_unchecked(groups) }
+    }
+
+    /// Removes consecutive duplicated items of the vector, consuming it and returning
+    /// the deduplicated vector along with the removed duplicates, in their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let (unique, duplicates) = non_empty_vec![1, 1, 2, 3, 3, 3, 1].into_partition_dedup();
+    ///
+    /// assert_eq!(unique, [1, 2, 3, 1]);
+    /// assert_eq!(duplicates, [1, 3, 3]);
+    /// ```
+    pub fn into_partition_dedup(self) -> (Self, Vec<T>)
+    where
+        T: PartialEq,
+    {
+        self.into_partition_dedup_by(T::eq)
+    }
+
+    /// Similar to [`into_partition_dedup`], but the duplicates are determined
+    /// by the given predicate.
+    ///
+    /// The predicate receives the last retained item and the next item,
+    /// returning whether the latter is a duplicate.
+    ///
+    /// [`into_partition_dedup`]: Self::into_partition_dedup
+    pub fn into_partition_dedup_by<P: FnMut(&T, &T) -> bool>(
+        self,
+        mut predicate: P,
+    ) -> (Self, Vec<T>) {
+        let (first, rest) = self.consume();
+
+        let mut unique = Self::single(first);
+
+        let mut duplicates = Vec::new();
+
+        for item in rest {
+            if predicate(unique.last(), &item) {
+                duplicates.push(item);
+            } else {
+                unique.push(item);
+            }
+        }
+
+        (unique, duplicates)
+    }
+
+    /// Partitions the items of the vector into the ones matching the given predicate
+    /// and the ones that do not, consuming the vector.
+    ///
+    /// Since the vector is non-empty, at least one of the sides is non-empty,
+    /// which is encoded in the returned [`Partitioned<T>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::{non_empty_vec, vec::Partitioned};
+    ///
+    /// let partitioned = non_empty_vec![1, 2, 3, 4, 5].into_partition(|item| item % 2 == 0);
+    ///
+    /// let Partitioned::Both(even, odd) = partitioned else {
+    ///     unreachable!();
+    /// };
+    ///
+    /// assert_eq!(even, [2, 4]);
+    /// assert_eq!(odd, [1, 3, 5]);
+    ///
+    /// let partitioned = non_empty_vec![1, 3].into_partition(|item| item % 2 == 0);
+    ///
+    /// assert!(matches!(partitioned, Partitioned::Unmatched(_)));
+    /// ```
+    pub fn into_partition<P: FnMut(&T) -> bool>(self, predicate: P) -> Partitioned<T> {
+        let (matched, unmatched) = self.into_iter().partition(predicate);
+
+        match (Self::new(matched), Self::new(unmatched)) {
+            (Ok(matched), Ok(unmatched)) => Partitioned::Both(matched, unmatched),
+            (Ok(matched), Err(_)) => Partitioned::Matched(matched),
+            (Err(_), Ok(unmatched)) => Partitioned::Unmatched(unmatched),
+            (Err(_), Err(_)) => {
+                unreachable!("partitioning non-empty vector yields at least one non-empty side")
+            }
+        }
+    }
+}
+
+/// Represents results of partitioning non-empty vectors, with at least one side non-empty.
+///
+/// This `enum` is returned from the [`into_partition`] method on [`NonEmptyVec<T>`].
+///
+/// [`into_partition`]: NonEmptyVec::into_partition
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Partitioned<T> {
+    /// All items matched the predicate.
+    Matched(NonEmptyVec<T>),
+    /// None of the items matched the predicate.
+    Unmatched(NonEmptyVec<T>),
+    /// Some items matched the predicate (first), and some did not (second).
+    Both(NonEmptyVec<T>, NonEmptyVec<T>),
+}
+
+impl<T> Partitioned<T> {
+    /// Returns the items that matched the predicate, if any.
+    #[must_use]
+    pub fn into_matched(self) -> Option<NonEmptyVec<T>> {
+        match self {
+            Self::Matched(matched) | Self::Both(matched, _) => Some(matched),
+            Self::Unmatched(_) => None,
+        }
+    }
+
+    /// Returns the items that did not match the predicate, if any.
+    #[must_use]
+    pub fn into_unmatched(self) -> Option<NonEmptyVec<T>> {
+        match self {
+            Self::Unmatched(unmatched) | Self::Both(_, unmatched) => Some(unmatched),
+            Self::Matched(_) => None,
+        }
+    }
+
+    /// Returns both sides as (possibly empty) vectors, like [`Iterator::partition`] does.
+    #[must_use]
+    pub fn into_vecs(self) -> (Vec<T>, Vec<T>) {
+        match self {
+            Self::Matched(matched) => (matched.into_vec(), Vec::new()),
+            Self::Unmatched(unmatched) => (Vec::new(), unmatched.into_vec()),
+            Self::Both(matched, unmatched) => (matched.into_vec(), unmatched.into_vec()),
+        }
+    }
 }
 
 impl<T: Clone> NonEmptyVec<T> {
@@ -899,6 +2644,62 @@ impl<T> NonEmptyVec<T> {
     }
 }
 
+impl<A, B> NonEmptyVec<(A, B)> {
+    /// Converts the vector of pairs into the pair of vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let pairs = non_empty_vec![(1, 'n'), (2, 'k')];
+    ///
+    /// let (numbers, characters) = pairs.unzip();
+    ///
+    /// assert_eq!(numbers.as_slice(), [1, 2]);
+    /// assert_eq!(characters.as_slice(), ['n', 'k']);
+    /// ```
+    pub fn unzip(self) -> (NonEmptyVec<A>, NonEmptyVec<B>) {
+        let (left, right) = self.into_iter().unzip();
+
+        // SAFETY: unzipping non-empty vector yields two non-empty vectors
+        unsafe {
+            (
+                NonEmptyVec::new_unchecked(left),
+                NonEmptyVec::new_unchecked(right),
+            )
+        }
+    }
+}
+
+impl<T: Default> NonEmptyVec<T> {
+    /// Constructs [`Self`] containing the single default value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::NonEmptyVec;
+    ///
+    /// let non_empty = NonEmptyVec::<u8>::of_default();
+    ///
+    /// assert_eq!(non_empty.as_slice(), [0]);
+    /// ```
+    #[must_use]
+    pub fn of_default() -> Self {
+        Self::single(T::default())
+    }
+
+    /// Constructs [`Self`] with the specified capacity, pushing the default value.
+    ///
+    /// # Panics
+    ///
+    /// Panics on capacity overflow.
+    #[must_use]
+    pub fn with_capacity_default(capacity: Size) -> Self {
+        Self::with_capacity_and_value(capacity, T::default())
+    }
+}
+
 impl<T> NonEmptyVec<T> {
     /// Returns regular by-reference iterator over the vector.
     pub fn iter(&self) -> Iter<'_, T> {
@@ -941,20 +2742,35 @@ impl<'a, T> IntoIterator for &'a mut NonEmptyVec<T> {
     }
 }
 
+// NOTE: `extend_one` and `extend_reserve` of `Extend` are unstable, so they can not be
+// overridden here; see the inherent `extend_one` and `extend_reserve` methods instead
+
 impl<T> Extend<T> for NonEmptyVec<T> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iterable: I) {
+        let iterator = iterable.into_iter();
+
+        let (lower, _) = iterator.size_hint();
+
+        self.extend_reserve(lower);
+
         // SAFETY: extending can not make the vector empty
         unsafe {
-            self.as_mut_vec().extend(iterable);
+            self.as_mut_vec().extend(iterator);
         }
     }
 }
 
 impl<'a, T: Copy + 'a> Extend<&'a T> for NonEmptyVec<T> {
     fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iterable: I) {
+        let iterator = iterable.into_iter();
+
+        let (lower, _) = iterator.size_hint();
+
+        self.extend_reserve(lower);
+
         // SAFETY: extending can not make the vector empty
         unsafe {
-            self.as_mut_vec().extend(iterable);
+            self.as_mut_vec().extend(iterator);
         }
     }
 }
@@ -981,6 +2797,27 @@ impl<T> NonEmptyVec<T> {
         // SAFETY: the slice is non-empty by construction
         unsafe { NonEmptyAdapter::new(self.iter_mut()) }
     }
+
+    /// Consumes the vector, returning the first item along with the iterator over the rest.
+    ///
+    /// This is the same as calling [`consume`] on the non-empty iterator of the vector,
+    /// without having to import [`NonEmptyIterator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use non_empty_slice::non_empty_vec;
+    ///
+    /// let (first, rest) = non_empty_vec![1, 2, 3].consume();
+    ///
+    /// assert_eq!(first, 1);
+    /// assert_eq!(rest.collect::<Vec<_>>(), [2, 3]);
+    /// ```
+    ///
+    /// [`consume`]: NonEmptyIterator::consume
+    pub fn consume(self) -> (T, IntoIter<T>) {
+        self.into_non_empty_iter().consume()
+    }
 }
 
 impl<T> FromNonEmptyIterator<T> for NonEmptyVec<T> {
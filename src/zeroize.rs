@@ -0,0 +1,45 @@
+#[cfg(not(feature = "zeroize"))]
+compile_error!("expected `zeroize` to be enabled");
+
+use zeroize::Zeroize;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use zeroize::ZeroizeOnDrop;
+
+use crate::slice::NonEmptySlice;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::{boxed::NonEmptyBoxedSlice, vec::NonEmptyVec};
+
+impl<Z: Zeroize> Zeroize for NonEmptySlice<Z> {
+    fn zeroize(&mut self) {
+        self.iter_mut().zeroize();
+    }
+}
+
+// NOTE: unlike `Vec<Z>`, `NonEmptyVec<Z>` can not be cleared, so the length is preserved;
+// the initialized items are zeroized in place, followed by the rest of the capacity
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<Z: Zeroize> Zeroize for NonEmptyVec<Z> {
+    fn zeroize(&mut self) {
+        self.as_non_empty_mut_slice().zeroize();
+
+        self.spare_capacity_mut().zeroize();
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<Z: ZeroizeOnDrop> ZeroizeOnDrop for NonEmptyVec<Z> {}
+
+// NOTE: `Zeroize` is not implemented for `Box<U>`, so we implement it for boxed slices directly
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<Z: Zeroize> Zeroize for NonEmptyBoxedSlice<Z> {
+    fn zeroize(&mut self) {
+        (**self).zeroize();
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<Z: ZeroizeOnDrop> ZeroizeOnDrop for NonEmptyBoxedSlice<Z> {}